@@ -3,12 +3,36 @@ use clap::Parser;
 
 #[derive(Parser)]
 struct Cli {
-    number: u32,
+    /// Convert a Greek numeral into an Arabic one instead
+    #[arg(short, long)]
+    reverse: bool,
+    /// Print the Greek numeral in lowercase
+    #[arg(short, long)]
+    lowercase: bool,
+    number: String,
 }
 
 fn main() {
     let args = Cli::parse();
 
-    let num: GreekNumeral = GreekNumeral::new(args.number).unwrap();
-    println!("{}", num);
+    let converted = if args.reverse {
+        args.number
+            .parse::<GreekNumeral>()
+            .map(|num| num.as_u32().to_string())
+    } else {
+        GreekNumeral::from_arabic_string(&args.number).map(|num| {
+            if args.lowercase {
+                num.to_lowercase()
+            } else {
+                num.to_uppercase()
+            }
+        })
+    };
+    match converted {
+        Ok(line) => println!("{line}"),
+        Err(err) => {
+            eprintln!("arabic2greek: {err}");
+            std::process::exit(1);
+        }
+    }
 }
@@ -0,0 +1,51 @@
+use std::process::Command;
+
+fn arabic2greek(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_arabic2greek"))
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn arabic2greek_err(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_arabic2greek"))
+        .args(args)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    String::from_utf8(output.stderr).unwrap()
+}
+
+#[test]
+fn test_uppercase_by_default() {
+    assert_eq!(arabic2greek(&["616"]), "ΧΙϜ'\n");
+}
+
+#[test]
+fn test_lowercase_flag() {
+    assert_eq!(arabic2greek(&["--lowercase", "616"]), "χιϝ'\n");
+    assert_eq!(arabic2greek(&["-l", "616"]), "χιϝ'\n");
+}
+
+#[test]
+fn test_grouped_input() {
+    assert_eq!(arabic2greek(&["1,984"]), "͵ΑϠΠΔ'\n");
+}
+
+#[test]
+fn test_reverse_flag() {
+    assert_eq!(arabic2greek(&["-r", "ΧΙϜ'"]), "616\n");
+    assert_eq!(arabic2greek(&["--reverse", "͵ΑϠΠΔ'"]), "1984\n");
+    assert_eq!(arabic2greek(&["-r", "χιϝ'"]), "616\n");
+}
+
+#[test]
+fn test_invalid_input_exits_with_error() {
+    assert!(arabic2greek_err(&["-r", "ΙΧ'"]).starts_with("arabic2greek: "));
+    assert!(arabic2greek_err(&["-r", "ΧΙϜ"]).starts_with("arabic2greek: "));
+    assert!(arabic2greek_err(&["6l6"]).starts_with("arabic2greek: "));
+    assert!(arabic2greek_err(&["10000000"]).starts_with("arabic2greek: "));
+}
@@ -0,0 +1,72 @@
+use std::hint::black_box;
+
+use arithmos::{Case, GreekNumeral, MAX, MAX_GLYPH_BYTES};
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+
+fn bench_to_uppercase(c: &mut Criterion) {
+    let numerals: Vec<GreekNumeral> = [1, 42, 616, 1984, 9999, MAX]
+        .into_iter()
+        .map(|i| GreekNumeral::new(i).unwrap())
+        .collect();
+    c.bench_function("to_uppercase", |b| {
+        b.iter(|| {
+            for num in &numerals {
+                black_box(black_box(*num).to_uppercase());
+            }
+        })
+    });
+}
+
+fn bench_to_lowercase(c: &mut Criterion) {
+    let numerals: Vec<GreekNumeral> = [1, 42, 616, 1984, 9999, MAX]
+        .into_iter()
+        .map(|i| GreekNumeral::new(i).unwrap())
+        .collect();
+    c.bench_function("to_lowercase", |b| {
+        b.iter(|| {
+            for num in &numerals {
+                black_box(black_box(*num).to_lowercase());
+            }
+        })
+    });
+}
+
+fn bench_full_range(c: &mut Criterion) {
+    let mut group = c.benchmark_group("full_range");
+    group.throughput(Throughput::Elements(u64::from(MAX) + 1));
+    group.sample_size(10);
+    group.bench_function("to_uppercase", |b| {
+        b.iter(|| {
+            for num in GreekNumeral::all() {
+                black_box(black_box(num).to_uppercase());
+            }
+        })
+    });
+    group.bench_function("write_to", |b| {
+        let mut buf = String::with_capacity(MAX_GLYPH_BYTES);
+        b.iter(|| {
+            for num in GreekNumeral::all() {
+                buf.clear();
+                black_box(num).write_to(&mut buf, Case::Upper);
+                black_box(&buf);
+            }
+        })
+    });
+    group.bench_function("render_into", |b| {
+        let mut buf = [0; MAX_GLYPH_BYTES];
+        b.iter(|| {
+            for num in GreekNumeral::all() {
+                let _ = black_box(black_box(num).render_into(&mut buf, Case::Upper));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_to_uppercase,
+    bench_to_lowercase,
+    bench_full_range
+);
+criterion_main!(benches);
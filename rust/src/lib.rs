@@ -14,12 +14,117 @@
 #![warn(clippy::print_stderr)]
 #![warn(clippy::print_stdout)]
 
+use core::cmp::Ordering;
 use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Sub, SubAssign};
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+#[cfg(feature = "std")]
+use std::collections::BTreeSet;
+
+#[cfg(feature = "std")]
+use table::ACROPHONIC;
+use table::{ARITHMOI, Arabic2GreekStruct, COMBINING_KERAIA, PLACES, THOUSANDS, ZERO};
+#[cfg(feature = "std")]
+use table::{BETA_CODE, BETA_CODE_THOUSANDS, ORDINAL, OVERLINE, ZERO_KERAIA};
+
+mod parse;
+mod table;
+
+pub use parse::{
+    ParseError, cmp_str, is_valid_greek_numeral, parse_lenient, parse_prefix, parse_with_case,
+    strip_keraia,
+};
+#[cfg(feature = "std")]
+pub use parse::{canonicalize, is_canonical};
 
 /// The value of the smallest Greek numeral
 pub const MIN: u32 = 0;
 /// The value of the largest Greek numeral
-pub const MAX: u32 = 999_999;
+///
+/// Derived from the largest entry in the conversion table: a table whose
+/// highest glyph is 9 × 10ⁿ can represent every value below 10ⁿ⁺¹.
+pub const MAX: u32 = ARITHMOI[0].arabic / 9 * 10 - 1;
+/// The number of glyphs in the conversion table, nine for each decimal place
+pub const NUMERAL_COUNT: usize = PLACES * 9;
+/// The most UTF-8 bytes any in-range numeral renders to in either ``Case``,
+/// keraia included
+///
+/// A buffer of this size always suffices for ``GreekNumeral::render_into``.
+pub const MAX_GLYPH_BYTES: usize = {
+    let mut total = '\''.len_utf8();
+    let mut place = 0;
+    while place < PLACES {
+        let mut widest = 0;
+        let mut digit = 0;
+        while digit < 9 {
+            let arithmos = &ARITHMOI[place * 9 + digit];
+            let upper = arithmos.attic(Case::Upper).len();
+            let lower = arithmos.attic(Case::Lower).len();
+            let len = if upper > lower { upper } else { lower };
+            if len > widest {
+                widest = len;
+            }
+            digit += 1;
+        }
+        total += widest;
+        place += 1;
+    }
+    total
+};
+
+// The range must be non-empty, cover every table glyph and match the number
+// of places the ``large-range`` feature selects.
+const _: () = assert!(MIN < MAX);
+const _: () = assert!(ARITHMOI[0].arabic <= MAX);
+const _: () = assert!(MAX == 10_u32.pow(PLACES as u32) - 1);
+
+/// Creates a ``GreekNumeral`` from a constant, checked at compile time.
+///
+/// A value above ``MAX`` is a compile error rather than a runtime
+/// ``OutOfRangeError``.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    const ANSWER: GreekNumeral = greek!(42);
+///    assert_eq!(ANSWER.to_string(), "ΜΒ'");
+///
+#[macro_export]
+macro_rules! greek {
+    ($value:expr) => {
+        const {
+            match $crate::GreekNumeral::new($value) {
+                ::core::result::Result::Ok(numeral) => numeral,
+                ::core::result::Result::Err(_) => ::core::panic!("Greek numeral out of range"),
+            }
+        }
+    };
+}
+
+/// Returns the glyph for a single table entry, such as 40 or 4000.
+///
+/// Returns ``None`` unless ``place_value`` is one non-zero digit followed by
+/// zeros within the supported range.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    assert_eq!(glyph_for(40, Case::Upper), Some("Μ"));
+///    assert_eq!(glyph_for(42, Case::Upper), None);
+///
+#[must_use]
+pub fn glyph_for(place_value: u32, case: Case) -> Option<&'static str> {
+    let place = place_value.checked_ilog10()?;
+    let digit = place_value / 10_u32.pow(place);
+    (digit * 10_u32.pow(place) == place_value && (place as usize) < PLACES)
+        .then(|| table::lookup(place as usize, digit).attic(case))
+}
 
 /// [`Result`](std::result::Result) with error defaulted to [`xvii::Error`](Error)
 pub type Result<T, E = OutOfRangeError> = core::result::Result<T, E>;
@@ -27,489 +132,1999 @@ pub type Result<T, E = OutOfRangeError> = core::result::Result<T, E>;
 /// Returned as an error if a numeral is constructed with an invalid input
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 #[non_exhaustive]
-pub struct OutOfRangeError;
+pub struct OutOfRangeError {
+    /// The value that was rejected
+    ///
+    /// Values above ``i128::MAX`` (only possible from a ``u128``) are
+    /// reported as ``i128::MAX``.
+    pub value: i128,
+    /// The largest value that would have been accepted
+    pub max: u32,
+}
+
+impl OutOfRangeError {
+    /// Creates an ``OutOfRangeError`` for ``value`` exceeding ``max``.
+    const fn new(value: i128, max: u32) -> Self {
+        Self { value, max }
+    }
+}
 
 impl fmt::Display for OutOfRangeError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "Number out of range (must be between 1 and 999,999).")
+        write!(
+            f,
+            "{} is out of range; expected {} to {}",
+            self.value,
+            MIN,
+            Grouped(self.max)
+        )
+    }
+}
+
+/// Displays a number with commas between groups of three digits
+struct Grouped(u32);
+
+impl fmt::Display for Grouped {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            0..1000 => write!(f, "{}", self.0),
+            n => write!(f, "{},{:03}", Grouped(n / 1000), n % 1000),
+        }
+    }
+}
+
+impl core::error::Error for OutOfRangeError {}
+
+/// Returned as an error if a numeral is constructed from an ``f64`` that
+/// does not round to a value in range
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum FromFloatError {
+    /// The value is NaN
+    NaN,
+    /// The value is positive or negative infinity
+    Infinite,
+    /// The rounded value is negative or greater than ``MAX``
+    OutOfRange(OutOfRangeError),
+}
+
+impl fmt::Display for FromFloatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::NaN => write!(f, "NaN is not a number; expected {MIN} to {}", Grouped(MAX)),
+            Self::Infinite => write!(
+                f,
+                "infinity is not a finite number; expected {MIN} to {}",
+                Grouped(MAX)
+            ),
+            Self::OutOfRange(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+impl core::error::Error for FromFloatError {}
+
+impl From<OutOfRangeError> for FromFloatError {
+    fn from(err: OutOfRangeError) -> Self {
+        Self::OutOfRange(err)
+    }
+}
+
+/// The letter case used when rendering a Greek numeral
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Case {
+    /// Uppercase glyphs, e.g. ``ΜΒ'``
+    #[default]
+    Upper,
+    /// Lowercase glyphs, e.g. ``μβ'``
+    Lower,
+}
+
+/// The glyph used for 900 and 900,000
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum SampiForm {
+    /// GREEK LETTER SAMPI, ``Ϡ``/``ϡ`` (U+03E0/U+03E1)
+    #[default]
+    Standard,
+    /// GREEK LETTER ARCHAIC SAMPI, ``Ͳ``/``ͳ`` (U+0372/U+0373)
+    Archaic,
+}
+
+impl SampiForm {
+    /// Returns the glyph for this form, with the thousands marker if needed.
+    const fn attic(self, case: Case, thousands: bool) -> &'static str {
+        match (self, case, thousands) {
+            (Self::Standard, Case::Upper, false) => "Ϡ",
+            (Self::Standard, Case::Lower, false) => "ϡ",
+            (Self::Standard, Case::Upper, true) => "͵Ϡ",
+            (Self::Standard, Case::Lower, true) => "͵ϡ",
+            (Self::Archaic, Case::Upper, false) => "Ͳ",
+            (Self::Archaic, Case::Lower, false) => "ͳ",
+            (Self::Archaic, Case::Upper, true) => "͵Ͳ",
+            (Self::Archaic, Case::Lower, true) => "͵ͳ",
+        }
+    }
+}
+
+/// The glyph used for 6 and 6,000
+///
+/// Editions differ on this glyph. The crate has always rendered digamma, so
+/// that remains the default.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum SixForm {
+    /// GREEK LETTER STIGMA, ``Ϛ``/``ϛ`` (U+03DA/U+03DB)
+    Stigma,
+    /// GREEK LETTER DIGAMMA, ``Ϝ``/``ϝ`` (U+03DC/U+03DD)
+    #[default]
+    Digamma,
+    /// Sigma and tau written out in full, ``ΣΤ``/``στ``
+    SigmaTau,
+}
+
+impl SixForm {
+    /// Returns the glyph for this form, with the thousands marker if needed.
+    const fn attic(self, case: Case, thousands: bool) -> &'static str {
+        match (self, case, thousands) {
+            (Self::Stigma, Case::Upper, false) => "Ϛ",
+            (Self::Stigma, Case::Lower, false) => "ϛ",
+            (Self::Stigma, Case::Upper, true) => "͵Ϛ",
+            (Self::Stigma, Case::Lower, true) => "͵ϛ",
+            (Self::Digamma, Case::Upper, false) => "Ϝ",
+            (Self::Digamma, Case::Lower, false) => "ϝ",
+            (Self::Digamma, Case::Upper, true) => "͵Ϝ",
+            (Self::Digamma, Case::Lower, true) => "͵ϝ",
+            (Self::SigmaTau, Case::Upper, false) => "ΣΤ",
+            (Self::SigmaTau, Case::Lower, false) => "στ",
+            (Self::SigmaTau, Case::Upper, true) => "͵Σ͵Τ",
+            (Self::SigmaTau, Case::Lower, true) => "͵σ͵τ",
+        }
+    }
+}
+
+/// The glyph used for 90 and 90,000
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum KoppaForm {
+    /// GREEK LETTER ARCHAIC KOPPA, ``Ϙ``/``ϙ`` (U+03D8/U+03D9)
+    #[default]
+    Archaic,
+    /// GREEK LETTER KOPPA, the numeral form ``Ϟ``/``ϟ`` (U+03DE/U+03DF)
+    Numeral,
+}
+
+impl KoppaForm {
+    /// Returns the glyph for this form, with the thousands marker if needed.
+    const fn attic(self, case: Case, thousands: bool) -> &'static str {
+        match (self, case, thousands) {
+            (Self::Archaic, Case::Upper, false) => "Ϙ",
+            (Self::Archaic, Case::Lower, false) => "ϙ",
+            (Self::Archaic, Case::Upper, true) => "͵Ϙ",
+            (Self::Archaic, Case::Lower, true) => "͵ϙ",
+            (Self::Numeral, Case::Upper, false) => "Ϟ",
+            (Self::Numeral, Case::Lower, false) => "ϟ",
+            (Self::Numeral, Case::Upper, true) => "͵Ϟ",
+            (Self::Numeral, Case::Lower, true) => "͵ϟ",
+        }
+    }
+}
+
+/// Options controlling how a Greek numeral is rendered
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    let opts = Format {
+///        with_keraia: false,
+///        ..Format::default()
+///    };
+///    assert_eq!("ΜΒ", GreekNumeral::new(42)?.format_with(opts));
+///
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Format<'a> {
+    /// The letter case of the glyphs (default: ``Case::Upper``)
+    pub case: Case,
+    /// Whether to append the trailing keraia (default: ``true``)
+    pub with_keraia: bool,
+    /// The glyph rendered for 0 (ZERO) (default: ``𐆊``, GREEK ZERO SIGN)
+    ///
+    /// Ancient Greek had no universal zero; editions also use ``ο`` or ``○``.
+    pub zero: &'a str,
+    /// The glyph for 900 and 900,000 (default: ``SampiForm::Standard``)
+    pub sampi: SampiForm,
+    /// The glyph for 6 and 6,000 (default: ``SixForm::Digamma``)
+    pub six: SixForm,
+    /// The glyph for 90 and 90,000 (default: ``KoppaForm::Archaic``)
+    pub koppa: KoppaForm,
+    /// Whether to mark the thousands as a group of their own (default:
+    /// ``false``)
+    ///
+    /// When set, a single lower keraia ``͵`` opens the thousands group in
+    /// place of one per glyph, and a keraia closes it, as in ``͵ΙΒ'ΣΛΔ'``
+    /// for 12,234. The trailing keraia is still controlled by
+    /// ``with_keraia``.
+    pub per_group_keraia: bool,
+    /// Whether a lowercase sigma ending the numeral is written as final sigma
+    /// ``ς`` (default: ``false``)
+    ///
+    /// This only applies when no glyph follows the sigma, as in ``ς'`` for
+    /// 200 or ``͵ας'`` for 1,200.
+    pub final_sigma: bool,
+    /// Whether the keraia is written as COMBINING ACUTE ACCENT (U+0301) over
+    /// the final glyph instead of a spacing ``'`` (default: ``false``)
+    ///
+    /// The accent is preceded by COMBINING GRAPHEME JOINER (U+034F) so that
+    /// glyphs ending in a vowel, such as ``Α`` for 1, are not composed into
+    /// tonos letters. Only applies when ``with_keraia`` is set.
+    pub combining_keraia: bool,
+    /// Whether 0 (ZERO) renders as nothing at all, with neither ``zero`` nor
+    /// a keraia (default: ``false``)
+    pub empty_zero: bool,
+    /// A string written between place glyphs, as in ``Μ Β`` for 42 (default:
+    /// ``None``)
+    ///
+    /// When set, the trailing keraia is left out whatever ``with_keraia``
+    /// says, since a spaced-out numeral is shown for its composition.
+    pub separator: Option<&'a str>,
+}
+
+impl Default for Format<'_> {
+    fn default() -> Self {
+        Self {
+            case: Case::Upper,
+            with_keraia: true,
+            zero: ZERO,
+            sampi: SampiForm::Standard,
+            six: SixForm::Digamma,
+            koppa: KoppaForm::Archaic,
+            per_group_keraia: false,
+            final_sigma: false,
+            combining_keraia: false,
+            empty_zero: false,
+            separator: None,
+        }
+    }
+}
+
+impl Format<'_> {
+    /// Returns the glyph these options select for an ``ARITHMOI`` entry.
+    const fn attic(&self, arithmos: &Arabic2GreekStruct<'static>) -> &'static str {
+        match arithmos.arabic {
+            6 => self.six.attic(self.case, false),
+            6000 => self.six.attic(self.case, true),
+            90 => self.koppa.attic(self.case, false),
+            90_000 => self.koppa.attic(self.case, true),
+            900 => self.sampi.attic(self.case, false),
+            900_000 => self.sampi.attic(self.case, true),
+            _ => arithmos.attic(self.case),
+        }
+    }
+}
+
+/// Counts the ``char``s written to it, for padding without allocating
+struct CharCount(usize);
+
+impl fmt::Write for CharCount {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Writes UTF-8 into the front of a byte slice, failing once it is full
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
     }
 }
 
 /// A Greek numeral
 ///
-/// Values from 0 to 999,9999 are currently supported
+/// Values from 0 to 999,999 are supported with the ``large-range`` feature
+/// (the default), or 0 to 9,999 without it
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct GreekNumeral(u32);
 
 impl GreekNumeral {
+    /// The smallest ``GreekNumeral``, 0 (ZERO)
+    pub const MIN: Self = match Self::new(crate::MIN) {
+        Ok(min) => min,
+        Err(_) => panic!("MIN is out of range"),
+    };
+
+    /// The largest ``GreekNumeral``
+    pub const MAX: Self = match Self::new(crate::MAX) {
+        Ok(max) => max,
+        Err(_) => panic!("MAX is out of range"),
+    };
+
     /// Creates a ``GreekNumeral`` for any value in range.
-    /// Requires ``value`` to be less than 10,000. 0 (ZERO) is acceptable.
+    ///
+    /// Requires ``value`` to be at most ``MAX``, which is 999,999 with the
+    /// ``large-range`` feature (the default) and 9,999 without it. 0 (ZERO) is
+    /// acceptable. Returns ``OutOfRangeError`` otherwise.
     ///
     /// Example
     /// -------
     ///
     /// .. code-block:: rust
     ///
-    //     let answer: GreekNumeral = GreekNumeral::new(42).unwrap();
-    //     assert_eq!("XLII", answer.to_uppercase());
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!("ΜΒ'", answer.to_uppercase());
     ///
     pub const fn new(value: u32) -> Result<Self, OutOfRangeError> {
-        if value <= 999_999 {
-            // SAFETY: 0 <= value <= 999,999
+        if value <= MAX {
+            // SAFETY: MIN <= value <= MAX
             Ok(Self(value))
         } else {
-            Err(OutOfRangeError)
+            Err(OutOfRangeError::new(value as i128, MAX))
         }
     }
 
-    /// Return the value of this ``GreekNumeral`` as a ``u32``.
+    /// Creates a ``GreekNumeral`` for any value, clamping it to ``MAX``.
+    ///
+    /// Prefer ``new`` unless out-of-range input should silently become ``MAX``.
     ///
     /// Example
     /// -------
     ///
     /// .. code-block:: rust
     ///
-    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
-    ///    assert_eq!(answer.as_u32(), 42_u32);
+    ///    let answer: GreekNumeral = GreekNumeral::new_clamped(1_000_000);
+    ///    assert_eq!(answer, GreekNumeral::MAX);
     ///
     #[must_use]
-    pub const fn as_u32(self) -> u32 {
-        self.0
+    pub const fn new_clamped(value: u32) -> Self {
+        match Self::new(value) {
+            Ok(numeral) => numeral,
+            Err(_) => Self::MAX,
+        }
     }
 
-    /// Converts a ``GreekNumeral`` to an uppercase string.
+    /// Creates a ``GreekNumeral`` from an ``f64``, rounded to the nearest
+    /// integer (halves away from zero).
+    ///
+    /// Returns ``FromFloatError::OutOfRange`` if the rounded value is negative
+    /// or greater than ``MAX``, and ``FromFloatError::NaN`` or
+    /// ``FromFloatError::Infinite`` if ``value`` is not finite.
     ///
     /// Example
     /// -------
     ///
     /// .. code-block:: rust
     ///
-    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
-    ///    assert_eq!("ΜΒ'", answer.to_uppercase());
+    ///    let answer: GreekNumeral = GreekNumeral::checked_from_f64(41.6)?;
+    ///    assert_eq!(answer.as_u32(), 42);
     ///
-    #[must_use]
     #[cfg(feature = "std")]
-    pub fn to_uppercase(self) -> String {
-        let mut out = String::new();
-        let mut n = self.0;
-        if n == 0 {
-            out.push_str(&"𐆊".to_string());
+    pub fn checked_from_f64(value: f64) -> Result<Self, FromFloatError> {
+        if value.is_nan() {
+            return Err(FromFloatError::NaN);
+        }
+        if value.is_infinite() {
+            return Err(FromFloatError::Infinite);
+        }
+        let rounded = value.round();
+        if (0.0..=f64::from(MAX)).contains(&rounded) {
+            Ok(Self(rounded as u32))
         } else {
-            for (_, arithmos) in ARITHMOI.iter().enumerate() {
-                while n >= arithmos.arabic {
-                    n -= arithmos.arabic;
-                    out.push_str(arithmos.u_attic);
-                }
-            }
+            Err(OutOfRangeError::new(rounded as i128, MAX).into())
         }
-        out.push_str(&"'".to_string());
-        out
     }
 
-    /// Converts a ``GreekNumeral`` to a lowercase string.
+    /// Creates a ``GreekNumeral`` from its thousands, hundreds, tens and units.
+    /// Each digit must be between 0 and 9 (inclusive).
     ///
     /// Example
     /// -------
     ///
     /// .. code-block:: rust
     ///
-    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
-    ///    assert_eq!("μβ'", answer.to_lowercase());
+    ///    let answer: GreekNumeral = GreekNumeral::from_digits(0, 0, 4, 2)?;
+    ///    assert_eq!(answer.as_u32(), 42);
     ///
-    #[must_use]
-    #[cfg(feature = "std")]
-    pub fn to_lowercase(self) -> String {
-        let mut out = String::new();
-        let mut n = self.0;
-        if n == 0 {
-            out.push_str(&"𐆊".to_string());
-        } else {
-            for (_, arithmos) in ARITHMOI.iter().enumerate() {
-                while n >= arithmos.arabic {
-                    n -= arithmos.arabic;
-                    out.push_str(arithmos.l_attic);
-                }
+    pub const fn from_digits(
+        thousands: u8,
+        hundreds: u8,
+        tens: u8,
+        units: u8,
+    ) -> Result<Self, OutOfRangeError> {
+        let mut i = 0;
+        let digits = [thousands, hundreds, tens, units];
+        while i < digits.len() {
+            if digits[i] > 9 {
+                return Err(OutOfRangeError::new(digits[i] as i128, 9));
             }
+            i += 1;
         }
-        out.push_str(&"'".to_string());
-        out
+        Self::new(thousands as u32 * 1000 + hundreds as u32 * 100 + tens as u32 * 10 + units as u32)
     }
-}
 
-#[cfg(feature = "std")]
-impl fmt::Display for GreekNumeral {
-    /// Converts a ``GreekNumeral`` to an uppercase string.
+    /// Creates a ``GreekNumeral`` for every value in a slice.
+    ///
+    /// Stops at the first out-of-range value and returns its error.
     ///
     /// Example
     /// -------
     ///
     /// .. code-block:: rust
     ///
-    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
-    ///    assert_eq!("ΜΒ'", answer.to_string());
+    ///    let numerals = GreekNumeral::try_from_slice(&[1, 42])?;
+    ///    assert_eq!(numerals, vec![GreekNumeral::new(1)?, GreekNumeral::new(42)?]);
     ///
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str(&self.to_uppercase())
+    #[cfg(feature = "std")]
+    pub fn try_from_slice(values: &[u32]) -> Result<Vec<Self>, OutOfRangeError> {
+        values.iter().map(|value| Self::new(*value)).collect()
     }
-}
-
-// based on https://en.wikipedia.org/wiki/Greek_numerals
-#[cfg(feature = "std")]
-struct Arabic2GreekStruct<'a> {
-    arabic: u32,
-    u_attic: &'a str,
-    l_attic: &'a str,
-}
 
-#[cfg(feature = "std")]
-static ARITHMOI: [Arabic2GreekStruct; 54] = [
-    Arabic2GreekStruct {
-        arabic: 900000,
-        u_attic: "͵Ϡ",
-        l_attic: "͵ϡ",
-    },
-    Arabic2GreekStruct {
-        arabic: 800000,
-        u_attic: "͵Ω",
-        l_attic: "͵ω",
-    },
-    Arabic2GreekStruct {
-        arabic: 700000,
-        u_attic: "͵Ψ",
-        l_attic: "͵ψ",
-    },
-    Arabic2GreekStruct {
-        arabic: 600000,
-        u_attic: "͵Χ",
-        l_attic: "͵χ",
-    },
-    Arabic2GreekStruct {
-        arabic: 500000,
-        u_attic: "͵Φ",
-        l_attic: "͵φ",
-    },
-    Arabic2GreekStruct {
-        arabic: 400000,
-        u_attic: "͵Υ",
-        l_attic: "͵υ",
-    },
-    Arabic2GreekStruct {
-        arabic: 300000,
-        u_attic: "͵Τ",
-        l_attic: "͵τ",
-    },
-    Arabic2GreekStruct {
-        arabic: 200000,
-        u_attic: "͵Σ",
-        l_attic: "͵σ",
-    },
-    Arabic2GreekStruct {
-        arabic: 100000,
-        u_attic: "͵Ρ",
-        l_attic: "͵ρ",
-    },
-    Arabic2GreekStruct {
-        arabic: 90000,
-        u_attic: "͵Ϙ",
-        l_attic: "͵ϙ",
-    },
-    Arabic2GreekStruct {
-        arabic: 80000,
-        u_attic: "͵Π",
-        l_attic: "͵π",
-    },
-    Arabic2GreekStruct {
-        arabic: 70000,
-        u_attic: "͵Ο",
-        l_attic: "͵ο",
-    },
-    Arabic2GreekStruct {
-        arabic: 60000,
-        u_attic: "͵Ξ",
-        l_attic: "͵ξ",
-    },
-    Arabic2GreekStruct {
-        arabic: 50000,
-        u_attic: "͵Ν",
-        l_attic: "͵ν",
-    },
-    Arabic2GreekStruct {
-        arabic: 40000,
-        u_attic: "͵Μ",
-        l_attic: "͵μ",
-    },
-    Arabic2GreekStruct {
-        arabic: 30000,
-        u_attic: "͵Λ",
-        l_attic: "͵λ",
-    },
-    Arabic2GreekStruct {
-        arabic: 20000,
-        u_attic: "͵Κ",
-        l_attic: "͵κ",
-    },
-    Arabic2GreekStruct {
-        arabic: 10000,
-        u_attic: "͵Ι",
-        l_attic: "͵ι",
-    },
-    Arabic2GreekStruct {
-        arabic: 9000,
-        u_attic: "͵Θ",
-        l_attic: "͵θ",
-    },
-    Arabic2GreekStruct {
-        arabic: 8000,
-        u_attic: "͵Η",
-        l_attic: "͵η",
-    },
-    Arabic2GreekStruct {
-        arabic: 7000,
-        u_attic: "͵Ζ",
-        l_attic: "͵ζ",
-    },
-    Arabic2GreekStruct {
-        arabic: 6000,
-        u_attic: "͵Ε",
-        l_attic: "͵ε",
-    },
-    Arabic2GreekStruct {
-        arabic: 5000,
-        u_attic: "͵Ε",
-        l_attic: "͵ε",
-    },
-    Arabic2GreekStruct {
-        arabic: 4000,
-        u_attic: "͵Δ",
-        l_attic: "͵δ",
-    },
-    Arabic2GreekStruct {
-        arabic: 3000,
-        u_attic: "͵Γ",
-        l_attic: "͵γ",
-    },
-    Arabic2GreekStruct {
-        arabic: 2000,
-        u_attic: "͵Β",
-        l_attic: "͵β",
-    },
-    Arabic2GreekStruct {
-        arabic: 1000,
-        u_attic: "͵Α",
-        l_attic: "͵α",
-    },
-    Arabic2GreekStruct {
-        arabic: 900,
-        u_attic: "Ϡ",
-        l_attic: "ϡ",
-    },
-    Arabic2GreekStruct {
-        arabic: 800,
-        u_attic: "Ω",
-        l_attic: "ω",
-    },
-    Arabic2GreekStruct {
-        arabic: 700,
-        u_attic: "Ψ",
-        l_attic: "ψ",
-    },
-    Arabic2GreekStruct {
-        arabic: 600,
-        u_attic: "Χ",
-        l_attic: "χ",
-    },
-    Arabic2GreekStruct {
-        arabic: 500,
-        u_attic: "Φ",
-        l_attic: "φ",
-    },
-    Arabic2GreekStruct {
-        arabic: 400,
-        u_attic: "Ρ",
-        l_attic: "ρ",
-    },
-    Arabic2GreekStruct {
-        arabic: 300,
-        u_attic: "Ρ",
-        l_attic: "ρ",
-    },
-    Arabic2GreekStruct {
-        arabic: 200,
-        u_attic: "Σ",
-        l_attic: "σ",
-    },
-    Arabic2GreekStruct {
-        arabic: 100,
-        u_attic: "Ρ",
-        l_attic: "ρ",
-    },
-    Arabic2GreekStruct {
-        arabic: 90,
-        u_attic: "Ϙ",
-        l_attic: "ϙ",
-    },
-    Arabic2GreekStruct {
-        arabic: 80,
-        u_attic: "Π",
-        l_attic: "π",
-    },
-    Arabic2GreekStruct {
-        arabic: 70,
-        u_attic: "Ο",
-        l_attic: "ο",
-    },
-    Arabic2GreekStruct {
-        arabic: 60,
-        u_attic: "Ξ",
-        l_attic: "ξ",
-    },
-    Arabic2GreekStruct {
-        arabic: 50,
-        u_attic: "Ν",
-        l_attic: "ν",
-    },
-    Arabic2GreekStruct {
-        arabic: 40,
-        u_attic: "Μ",
-        l_attic: "μ",
-    },
-    Arabic2GreekStruct {
-        arabic: 30,
-        u_attic: "Λ",
-        l_attic: "λ",
-    },
-    Arabic2GreekStruct {
-        arabic: 20,
-        u_attic: "Κ",
-        l_attic: "κ",
-    },
-    Arabic2GreekStruct {
-        arabic: 10,
-        u_attic: "Ι",
-        l_attic: "ι",
-    },
-    Arabic2GreekStruct {
-        arabic: 9,
-        u_attic: "Θ",
-        l_attic: "θ",
-    },
-    Arabic2GreekStruct {
-        arabic: 8,
-        u_attic: "Η",
-        l_attic: "η",
-    },
-    Arabic2GreekStruct {
-        arabic: 7,
-        u_attic: "Ζ",
-        l_attic: "ζ",
-    },
-    Arabic2GreekStruct {
-        arabic: 6,
-        u_attic: "Ϝ",
-        l_attic: "ϝ",
-    },
-    Arabic2GreekStruct {
-        arabic: 5,
-        u_attic: "Ε",
-        l_attic: "ε",
-    },
-    Arabic2GreekStruct {
-        arabic: 4,
-        u_attic: "Δ",
-        l_attic: "δ",
-    },
-    Arabic2GreekStruct {
-        arabic: 3,
-        u_attic: "Γ",
-        l_attic: "γ",
-    },
-    Arabic2GreekStruct {
-        arabic: 2,
-        u_attic: "Β",
-        l_attic: "β",
-    },
-    Arabic2GreekStruct {
-        arabic: 1,
-        u_attic: "Α",
-        l_attic: "α",
-    },
-];
-
-impl TryFrom<u8> for GreekNumeral {
-    type Error = OutOfRangeError;
-
-    /// Creates a ``GreekNumeral`` from an ``u8``.
+    /// Converts every ``GreekNumeral`` in a slice to an uppercase string.
     ///
-    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
-    fn try_from(value: u8) -> Result<Self, OutOfRangeError> {
-        Self::new(u32::from(value))
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let numerals = GreekNumeral::try_from_slice(&[1, 42])?;
+    ///    assert_eq!(GreekNumeral::to_strings(&numerals), vec!["Α'", "ΜΒ'"]);
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn to_strings(nums: &[Self]) -> Vec<String> {
+        nums.iter().map(|num| num.to_uppercase()).collect()
     }
-}
-
-impl TryFrom<u16> for GreekNumeral {
-    type Error = OutOfRangeError;
 
-    /// Creates a ``GreekNumeral`` from an ``u16``.
+    /// Iterates over the ``GreekNumeral`` values from ``start`` (inclusive) to
+    /// ``end`` (exclusive).
     ///
-    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
-    fn try_from(value: u16) -> Result<Self, OutOfRangeError> {
-        Self::new(u32::from(value))
+    /// Values above ``MAX`` are skipped, so the iterator ends early if
+    /// ``end`` is out of range.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let mut numerals = GreekNumeral::range(1, 3);
+    ///    assert_eq!(numerals.next(), Some(GreekNumeral::new(1)?));
+    ///    assert_eq!(numerals.next(), Some(GreekNumeral::new(2)?));
+    ///    assert_eq!(numerals.next(), None);
+    ///
+    pub fn range(start: u32, end: u32) -> impl Iterator<Item = Self> {
+        (start..end).map_while(|value| Self::new(value).ok())
     }
-}
-
-impl TryFrom<u32> for GreekNumeral {
-    type Error = OutOfRangeError;
 
-    /// Creates a ``GreekNumeral`` from an ``u32``.
+    /// Iterates over every ``GreekNumeral`` from ``MIN`` to ``MAX`` inclusive.
     ///
-    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
-    fn try_from(value: u32) -> Result<Self, OutOfRangeError> {
-        Self::new(value)
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    for numeral in GreekNumeral::all() {
+    ///        assert!(numeral.to_string().ends_with('\''));
+    ///    }
+    ///
+    pub fn all() -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        (MIN..MAX + 1).map(Self)
     }
-}
 
-impl TryFrom<u64> for GreekNumeral {
-    type Error = OutOfRangeError;
-
-    /// Creates a ``GreekNumeral`` from an ``u64``.
+    /// Return the value of this ``GreekNumeral`` as a ``u32``.
     ///
-    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
-    fn try_from(value: u64) -> Result<Self, OutOfRangeError> {
-        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!(answer.as_u32(), 42_u32);
+    ///
+    #[must_use]
+    pub const fn as_u32(self) -> u32 {
+        self.0
     }
-}
-
-impl TryFrom<u128> for GreekNumeral {
-    type Error = OutOfRangeError;
 
-    /// Creates a ``GreekNumeral`` from an ``u128``.
+    /// Returns the value of this ``GreekNumeral`` as a decimal string with
+    /// commas between groups of three digits.
     ///
-    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
-    fn try_from(value: u128) -> Result<Self, OutOfRangeError> {
-        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(9999)?;
+    ///    assert_eq!("9,999", answer.to_arabic_string());
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn to_arabic_string(self) -> String {
+        Grouped(self.0).to_string()
     }
-}
-
-impl TryFrom<usize> for GreekNumeral {
-    type Error = OutOfRangeError;
 
-    /// Creates a ``GreekNumeral`` from an ``usize``.
+    /// Returns the thousands, hundreds, tens and units of this ``GreekNumeral``.
     ///
-    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
-    fn try_from(value: usize) -> Result<Self, OutOfRangeError> {
-        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    /// This is the inverse of ``from_digits``. Places of 10,000 and above are
+    /// not included.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(1984)?;
+    ///    assert_eq!(answer.digits(), [1, 9, 8, 4]);
+    ///
+    #[must_use]
+    pub const fn digits(self) -> [u8; 4] {
+        [
+            (self.0 / 1000 % 10) as u8,
+            (self.0 / 100 % 10) as u8,
+            (self.0 / 10 % 10) as u8,
+            (self.0 % 10) as u8,
+        ]
     }
-}
-
-impl TryFrom<i8> for GreekNumeral {
-    type Error = OutOfRangeError;
+
+    /// Returns how many decimal places this ``GreekNumeral`` occupies.
+    ///
+    /// Zero occupies no places.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(1984)?;
+    ///    assert_eq!(answer.place_count(), 4);
+    ///
+    #[must_use]
+    pub const fn place_count(self) -> u32 {
+        match self.0.checked_ilog10() {
+            Some(log) => log + 1,
+            None => 0,
+        }
+    }
+
+    /// Returns whether this ``GreekNumeral`` has glyphs of 1,000 or more,
+    /// which are written with the ``͵`` thousands marker.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(1984)?;
+    ///    assert!(answer.has_thousands());
+    ///
+    #[must_use]
+    pub const fn has_thousands(self) -> bool {
+        self.0 >= 1000
+    }
+
+    /// Returns whether the value of a ``GreekNumeral`` is even.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert!(answer.is_even());
+    ///
+    #[must_use]
+    pub const fn is_even(self) -> bool {
+        self.0.is_multiple_of(2)
+    }
+
+    /// Returns whether the value of a ``GreekNumeral`` is a multiple of ``d``.
+    ///
+    /// Only 0 (ZERO) is divisible by 0.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert!(answer.is_divisible_by(7));
+    ///
+    #[must_use]
+    pub const fn is_divisible_by(self, d: u32) -> bool {
+        self.0.is_multiple_of(d)
+    }
+
+    /// Returns the value of the highest place glyph in this ``GreekNumeral``,
+    /// the leading digit followed by zeros.
+    ///
+    /// Returns 0 for 0 (ZERO).
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(2456)?;
+    ///    assert_eq!(answer.leading_place(), 2000);
+    ///
+    #[must_use]
+    pub const fn leading_place(self) -> u32 {
+        match self.0.checked_ilog10() {
+            Some(log) => {
+                let place = 10_u32.pow(log);
+                self.0 / place * place
+            }
+            None => 0,
+        }
+    }
+
+    /// Returns the smallest power of ten greater than this ``GreekNumeral``,
+    /// or ``None`` if it would exceed ``MAX``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!(answer.next_decade(), GreekNumeral::new(100).ok());
+    ///
+    #[must_use]
+    pub const fn next_decade(self) -> Option<Self> {
+        let power = match self.0.checked_ilog10() {
+            Some(log) => 10_u32.pow(log + 1),
+            None => 1,
+        };
+        match Self::new(power) {
+            Ok(decade) => Some(decade),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns the next ``GreekNumeral``, or ``None`` at ``MAX``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(41)?;
+    ///    assert_eq!(answer.succ(), Some(GreekNumeral::new(42)?));
+    ///
+    #[must_use]
+    pub const fn succ(self) -> Option<Self> {
+        if self.0 < MAX {
+            Some(Self(self.0 + 1))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the previous ``GreekNumeral``, or ``None`` at ``MIN``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(43)?;
+    ///    assert_eq!(answer.pred(), Some(GreekNumeral::new(42)?));
+    ///
+    #[must_use]
+    pub const fn pred(self) -> Option<Self> {
+        if self.0 > MIN {
+            Some(Self(self.0 - 1))
+        } else {
+            None
+        }
+    }
+
+    /// Adds two ``GreekNumeral`` values, returning ``None`` past ``MAX``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer = GreekNumeral::new(40)?.checked_add(GreekNumeral::new(2)?);
+    ///    assert_eq!(answer, Some(GreekNumeral::new(42)?));
+    ///
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match Self::new(self.0 + rhs.0) {
+            Ok(sum) => Some(sum),
+            Err(_) => None,
+        }
+    }
+
+    /// Subtracts two ``GreekNumeral`` values, returning ``None`` below ``MIN``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer = GreekNumeral::new(2)?.checked_sub(GreekNumeral::new(40)?);
+    ///    assert_eq!(answer, None);
+    ///
+    #[must_use]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(difference) => Some(Self(difference)),
+            None => None,
+        }
+    }
+
+    /// Multiplies a ``GreekNumeral`` by ``factor``, returning ``None`` past
+    /// ``MAX``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer = GreekNumeral::new(1000)?.checked_mul(9);
+    ///    assert_eq!(answer, GreekNumeral::new(9000).ok());
+    ///
+    #[must_use]
+    pub const fn checked_mul(self, factor: u32) -> Option<Self> {
+        match self.0.checked_mul(factor) {
+            Some(product) => match Self::new(product) {
+                Ok(product) => Some(product),
+                Err(_) => None,
+            },
+            None => None,
+        }
+    }
+
+    /// Adds two ``GreekNumeral`` values, clamping the result to ``MAX``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer = GreekNumeral::new(40)?.saturating_add(GreekNumeral::new(2)?);
+    ///    assert_eq!(answer.as_u32(), 42);
+    ///
+    #[must_use]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        match Self::new(self.0 + rhs.0) {
+            Ok(sum) => sum,
+            Err(_) => Self(MAX),
+        }
+    }
+
+    /// Subtracts two ``GreekNumeral`` values, clamping the result to ``MIN``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer = GreekNumeral::new(2)?.saturating_sub(GreekNumeral::new(40)?);
+    ///    assert_eq!(answer.as_u32(), 0);
+    ///
+    #[must_use]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        match Self::new(self.0.saturating_sub(rhs.0)) {
+            Ok(difference) => difference,
+            Err(_) => Self(MIN),
+        }
+    }
+
+    /// Adds two ``GreekNumeral`` values modulo ``MAX + 1``, wrapping past
+    /// ``MAX`` back around to ``MIN``.
+    ///
+    /// The modulus follows the ``large-range`` feature: 1,000,000 by default,
+    /// or 10,000 without it.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer = GreekNumeral::MAX.wrapping_add(GreekNumeral::new(43)?);
+    ///    assert_eq!(answer.as_u32(), 42);
+    ///
+    #[must_use]
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        Self((self.0 + rhs.0) % (MAX + 1))
+    }
+
+    /// Converts a ``GreekNumeral`` to an uppercase string.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!("ΜΒ'", answer.to_uppercase());
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn to_uppercase(self) -> String {
+        let mut out = String::with_capacity(self.byte_len(Case::Upper));
+        self.write_to(&mut out, Case::Upper);
+        out
+    }
+
+    /// Converts a ``GreekNumeral`` to a lowercase string.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!("μβ'", answer.to_lowercase());
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn to_lowercase(self) -> String {
+        let mut out = String::with_capacity(self.byte_len(Case::Lower));
+        self.write_to(&mut out, Case::Lower);
+        out
+    }
+
+    /// Converts a ``GreekNumeral`` to a string in the given ``Case``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!("μβ'", answer.to_string_with_case(Case::Lower));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn to_string_with_case(self, case: Case) -> String {
+        let mut out = String::with_capacity(self.byte_len(case));
+        self.write_to(&mut out, case);
+        out
+    }
+
+    /// Converts a ``GreekNumeral`` to uppercase and lowercase strings at once,
+    /// walking the conversion table only once.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!(answer.to_both_cases(), (String::from("ΜΒ'"), String::from("μβ'")));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn to_both_cases(self) -> (String, String) {
+        let mut upper = String::new();
+        let mut lower = String::new();
+        if self.0 == 0 {
+            upper.push_str(ZERO);
+            lower.push_str(ZERO);
+        }
+        for arithmos in self.arithmoi() {
+            upper.push_str(arithmos.u_attic);
+            lower.push_str(arithmos.l_attic);
+        }
+        upper.push('\'');
+        lower.push('\'');
+        (upper, lower)
+    }
+
+    /// Converts a ``GreekNumeral`` to a string in the given ``Case``, borrowing
+    /// it when possible.
+    ///
+    /// Values written with a single glyph (0, 1 to 9, 10, 20 and so on)
+    /// are borrowed from the conversion table without allocating. All other
+    /// values are rendered into an owned ``String``. Either way the text
+    /// matches ``to_string_with_case``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let one: GreekNumeral = GreekNumeral::new(1)?;
+    ///    assert!(matches!(one.to_cow(Case::Upper), Cow::Borrowed("Α'")));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn to_cow(self, case: Case) -> Cow<'static, str> {
+        if self.0 == MIN {
+            return Cow::Borrowed(ZERO_KERAIA);
+        }
+        let mut arithmoi = self.arithmoi();
+        match (arithmoi.next(), arithmoi.next()) {
+            (Some(arithmos), None) => Cow::Borrowed(arithmos.with_keraia(case)),
+            _ => Cow::Owned(self.to_string_with_case(case)),
+        }
+    }
+
+    /// Returns the characters of a ``GreekNumeral`` in the given ``Case``,
+    /// ending with the keraia.
+    ///
+    /// Each codepoint is its own ``char``, so a thousands glyph such as ``͵Α``
+    /// is two elements: the ``͵`` marker followed by the letter.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(1042)?;
+    ///    assert_eq!(answer.glyphs(Case::Upper), vec!['͵', 'Α', 'Μ', 'Β', '\'']);
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn glyphs(self, case: Case) -> Vec<char> {
+        self.to_string_with_case(case).chars().collect()
+    }
+
+    /// Returns the distinct characters of a ``GreekNumeral`` in the given
+    /// ``Case``, leaving out the keraia.
+    ///
+    /// A typesetter can union these across a document to build a minimal font
+    /// subset. The thousands marker ``͵`` is included when present.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(2002)?;
+    ///    assert_eq!(answer.distinct_glyphs(Case::Upper), BTreeSet::from(['͵', 'Β']));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn distinct_glyphs(self, case: Case) -> BTreeSet<char> {
+        self.format_with(Format {
+            case,
+            with_keraia: false,
+            ..Format::default()
+        })
+        .chars()
+        .collect()
+    }
+
+    /// Returns whether two ``GreekNumeral`` values share any letter in the
+    /// given ``Case``.
+    ///
+    /// The thousands marker ``͵`` is not a letter and is ignored, so ``͵Α``
+    /// and ``Α`` share alpha but ``͵Α`` and ``͵Β`` share nothing.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert!(answer.shares_glyph_with(GreekNumeral::new(40)?, Case::Upper));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn shares_glyph_with(self, other: Self, case: Case) -> bool {
+        let mut theirs = other.distinct_glyphs(case);
+        theirs.remove(&THOUSANDS);
+        !self.distinct_glyphs(case).is_disjoint(&theirs)
+    }
+
+    /// Returns the UTF-8 encoding of a ``GreekNumeral`` in the given ``Case``,
+    /// including the keraia.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!(answer.as_greek_bytes(Case::Upper), "ΜΒ'".as_bytes());
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn as_greek_bytes(self, case: Case) -> Vec<u8> {
+        self.to_string_with_case(case).into_bytes()
+    }
+
+    /// Returns the UTF-16 code units of a ``GreekNumeral`` in the given
+    /// ``Case``, including the keraia, for runtimes such as Windows or the
+    /// JVM.
+    ///
+    /// The letters, ``͵`` and the keraia are each one code unit; only 0
+    /// (ZERO), outside the Basic Multilingual Plane, takes a surrogate pair.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!(answer.to_utf16(Case::Upper), [0x39c, 0x392, 0x27]);
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn to_utf16(self, case: Case) -> Vec<u16> {
+        self.to_string_with_case(case).encode_utf16().collect()
+    }
+
+    /// Writes a ``GreekNumeral`` in the given ``Case``, with its keraia, into
+    /// the front of ``buf`` and returns the written part as a ``&str``.
+    ///
+    /// This needs no allocation; a buffer of ``MAX_GLYPH_BYTES`` fits any
+    /// value. Returns ``fmt::Error`` if ``buf`` is too short.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let mut buf = [0; MAX_GLYPH_BYTES];
+    ///    assert_eq!(GreekNumeral::new(42)?.render_into(&mut buf, Case::Upper)?, "ΜΒ'");
+    ///
+    pub fn render_into(self, buf: &mut [u8], case: Case) -> Result<&str, fmt::Error> {
+        let mut writer = SliceWriter { buf, len: 0 };
+        let opts = Format {
+            case,
+            ..Format::default()
+        };
+        self.render(&mut writer, opts)?;
+        let SliceWriter { buf, len } = writer;
+        core::str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)
+    }
+
+    /// Appends a ``GreekNumeral`` in the given ``Case``, with its keraia, to
+    /// an existing ``String``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let mut buf = String::from("Book ");
+    ///    GreekNumeral::new(42)?.write_to(&mut buf, Case::Upper);
+    ///    assert_eq!("Book ΜΒ'", buf);
+    ///
+    #[cfg(feature = "std")]
+    pub fn write_to(self, buf: &mut String, case: Case) {
+        let opts = Format {
+            case,
+            ..Format::default()
+        };
+        // Writing to a String never fails.
+        let _ = self.render(buf, opts);
+    }
+
+    /// Writes a ``GreekNumeral`` in the given ``Case``, with its keraia, to a
+    /// ``std::io::Write`` without building an intermediate ``String``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let mut out = std::io::stdout().lock();
+    ///    GreekNumeral::new(42)?.write_io(&mut out, Case::Upper)?;
+    ///
+    #[cfg(feature = "std")]
+    pub fn write_io<W: std::io::Write>(self, w: &mut W, case: Case) -> std::io::Result<()> {
+        match case {
+            Case::Upper => write!(w, "{self}"),
+            Case::Lower => write!(w, "{}", LowerGreek(self)),
+        }
+    }
+
+    /// Converts a ``GreekNumeral`` to a string using the given ``Format``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    let opts = Format {
+    ///        case: Case::Lower,
+    ///        with_keraia: false,
+    ///        ..Format::default()
+    ///    };
+    ///    assert_eq!("μβ", answer.format_with(opts));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn format_with(self, opts: Format) -> String {
+        let mut out = String::new();
+        // Writing to a String never fails.
+        let _ = self.render(&mut out, opts);
+        out
+    }
+
+    /// Writes a ``GreekNumeral`` rendered with ``opts`` to ``w``, one glyph at
+    /// a time.
+    fn render<W: fmt::Write>(self, w: &mut W, opts: Format) -> fmt::Result {
+        if self.0 == 0 && opts.empty_zero {
+            return Ok(());
+        }
+        let with_keraia = opts.with_keraia && opts.separator.is_none();
+        if self.0 == 0 {
+            w.write_str(opts.zero)?;
+        } else {
+            let mut in_thousands = false;
+            let mut arithmoi = self.arithmoi().peekable();
+            let mut first = true;
+            while let Some(arithmos) = arithmoi.next() {
+                let glyph = match opts.attic(arithmos) {
+                    "σ" if opts.final_sigma && arithmoi.peek().is_none() => "ς",
+                    "͵σ" if opts.final_sigma && arithmoi.peek().is_none() => "͵ς",
+                    glyph => glyph,
+                };
+                let thousands = opts.per_group_keraia && arithmos.arabic >= 1000;
+                if in_thousands && !thousands {
+                    w.write_char('\'')?;
+                }
+                if let (false, Some(separator)) = (first, opts.separator) {
+                    w.write_str(separator)?;
+                }
+                first = false;
+                if !thousands {
+                    in_thousands = false;
+                    w.write_str(glyph)?;
+                    continue;
+                }
+                if !in_thousands {
+                    w.write_char(THOUSANDS)?;
+                }
+                in_thousands = true;
+                for c in glyph.chars().filter(|&c| c != THOUSANDS) {
+                    w.write_char(c)?;
+                }
+            }
+            if in_thousands && !with_keraia {
+                w.write_char('\'')?;
+            }
+        }
+        if with_keraia {
+            if opts.combining_keraia {
+                w.write_str(COMBINING_KERAIA)?;
+            } else {
+                w.write_char('\'')?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes ``sign`` and a ``GreekNumeral`` rendered with ``opts`` to a
+    /// ``Formatter``, padded to its width by ``char`` count.
+    fn display(self, f: &mut fmt::Formatter, sign: &str, opts: Format) -> fmt::Result {
+        use core::fmt::Write;
+
+        let Some(width) = f.width() else {
+            f.write_str(sign)?;
+            return self.render(f, opts);
+        };
+        let mut count = CharCount(sign.chars().count());
+        self.render(&mut count, opts)?;
+        let padding = width.saturating_sub(count.0);
+        let (before, after) = match f.align() {
+            Some(fmt::Alignment::Right) => (padding, 0),
+            Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(fmt::Alignment::Left) | None => (0, padding),
+        };
+        let fill = f.fill();
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+        f.write_str(sign)?;
+        self.render(f, opts)?;
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+        Ok(())
+    }
+
+    /// Converts a ``GreekNumeral`` to its glyphs in the given ``Case`` and
+    /// returns them apart from the keraia, for styling the two differently.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!(answer.stem_and_keraia(Case::Upper), (String::from("ΜΒ"), "'"));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn stem_and_keraia(self, case: Case) -> (String, &'static str) {
+        let stem = self.format_with(Format {
+            case,
+            with_keraia: false,
+            ..Format::default()
+        });
+        (stem, "'")
+    }
+
+    /// Converts a ``GreekNumeral`` to an uppercase string with an overline.
+    ///
+    /// Every letter is followed by COMBINING OVERLINE (U+0305) so the whole
+    /// numeral renders overlined. The overline replaces the keraia, and the
+    /// thousands marker ``͵`` is left unmarked.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!("Μ\u{305}Β\u{305}", answer.to_overlined());
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn to_overlined(self) -> String {
+        let letters = self.format_with(Format {
+            with_keraia: false,
+            ..Format::default()
+        });
+        let mut out = String::new();
+        for c in letters.chars() {
+            out.push(c);
+            if c != THOUSANDS {
+                out.push(OVERLINE);
+            }
+        }
+        out
+    }
+
+    /// Converts a ``GreekNumeral`` to HTML with each glyph in its own span.
+    ///
+    /// Every place glyph is wrapped in a ``greek-place`` span carrying its
+    /// value in ``data-value``, and the keraia in a ``greek-keraia`` span, so
+    /// stylesheets can style or annotate each place.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(2)?;
+    ///    assert_eq!(
+    ///        answer.to_html(Case::Upper),
+    ///        r#"<span class="greek-place" data-value="2">Β</span><span class="greek-keraia">'</span>"#,
+    ///    );
+    ///
+    #[must_use]
+    #[cfg(feature = "html")]
+    pub fn to_html(self, case: Case) -> String {
+        use core::fmt::Write;
+
+        let mut out = String::new();
+        if self.0 == 0 {
+            let _ = write!(
+                out,
+                r#"<span class="greek-place" data-value="0">{ZERO}</span>"#
+            );
+        }
+        for arithmos in self.arithmoi() {
+            let _ = write!(
+                out,
+                r#"<span class="greek-place" data-value="{}">{}</span>"#,
+                arithmos.arabic,
+                arithmos.attic(case),
+            );
+        }
+        out.push_str(r#"<span class="greek-keraia">'</span>"#);
+        out
+    }
+
+    /// Converts a ``GreekNumeral`` to LaTeX for the ``babel`` package's
+    /// ``polutonikogreek`` option.
+    ///
+    /// The glyphs are wrapped in ``\textgreek`` and the keraia is written as
+    /// ``\textdexiakeraia``, since a bare ``'`` is read as an accent in Greek
+    /// text.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!(answer.to_tex(Case::Upper), r"\textgreek{ΜΒ\textdexiakeraia}");
+    ///
+    #[must_use]
+    #[cfg(feature = "tex")]
+    pub fn to_tex(self, case: Case) -> String {
+        let letters = self.format_with(Format {
+            case,
+            with_keraia: false,
+            ..Format::default()
+        });
+        format!(r"\textgreek{{{letters}\textdexiakeraia}}")
+    }
+
+    /// Converts a ``GreekNumeral`` to a Roman numeral, for comparing the two
+    /// systems.
+    ///
+    /// Returns ``None`` for 0 (ZERO) and values above 3,999, which standard
+    /// Roman numerals cannot write.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!(answer.to_roman(), Some(String::from("XLII")));
+    ///
+    #[must_use]
+    #[cfg(feature = "roman")]
+    pub fn to_roman(self) -> Option<String> {
+        const ROMAN: [(u32, &str); 13] = [
+            (1000, "M"),
+            (900, "CM"),
+            (500, "D"),
+            (400, "CD"),
+            (100, "C"),
+            (90, "XC"),
+            (50, "L"),
+            (40, "XL"),
+            (10, "X"),
+            (9, "IX"),
+            (5, "V"),
+            (4, "IV"),
+            (1, "I"),
+        ];
+        if !(1..=3999).contains(&self.0) {
+            return None;
+        }
+        let mut out = String::new();
+        let mut rest = self.0;
+        for (value, letters) in ROMAN {
+            while rest >= value {
+                out.push_str(letters);
+                rest -= value;
+            }
+        }
+        Some(out)
+    }
+
+    /// Transliterates a ``GreekNumeral`` into ASCII Beta Code.
+    ///
+    /// Letters use their Beta Code equivalents (``M`` for mu, ``V`` for
+    /// digamma, ``#3`` for koppa and ``#5`` for sampi), with ``*`` before each
+    /// capital in ``Case::Upper``. The thousands marker is ``#22`` and the
+    /// keraia ``'``. Beta Code has no zero sign, so 0 (ZERO) is written ``0``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!("*M*B'", answer.to_ascii_translit(Case::Upper));
+    ///    assert_eq!("MB'", answer.to_ascii_translit(Case::Lower));
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn to_ascii_translit(self, case: Case) -> String {
+        let mut out = String::new();
+        if self.0 == 0 {
+            out.push('0');
+        }
+        for arithmos in self.arithmoi() {
+            for c in arithmos.l_attic.chars() {
+                if c == THOUSANDS {
+                    out.push_str(BETA_CODE_THOUSANDS);
+                    continue;
+                }
+                if case == Case::Upper {
+                    out.push('*');
+                }
+                if let Some(&(_, code)) = BETA_CODE.iter().find(|&&(glyph, _)| glyph == c) {
+                    out.push_str(code);
+                }
+            }
+        }
+        out.push('\'');
+        out
+    }
+
+    /// Converts a ``GreekNumeral`` to an uppercase ordinal string.
+    ///
+    /// Ordinals follow the modern convention for numbered chapters and
+    /// sections: the keraia is replaced by the masculine ordinal ending
+    /// ``ος``, so 1 reads as "first" (πρῶτος) rather than "one". The numeral
+    /// itself is always uppercase so it stays distinct from the ending.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let chapter: GreekNumeral = GreekNumeral::new(2)?;
+    ///    assert_eq!("Βος", chapter.to_ordinal());
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn to_ordinal(self) -> String {
+        let mut out = self.format_with(Format {
+            with_keraia: false,
+            ..Format::default()
+        });
+        out.push_str(ORDINAL);
+        out
+    }
+
+    /// Iterates over the uppercase glyphs making up a ``GreekNumeral``.
+    ///
+    /// Yields the place value and glyph of each table entry in output order.
+    /// The keraia is not included, and 0 (ZERO) yields nothing.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    let mut glyphs = answer.components();
+    ///    assert_eq!(glyphs.next(), Some((40, "Μ")));
+    ///    assert_eq!(glyphs.next(), Some((2, "Β")));
+    ///    assert_eq!(glyphs.next(), None);
+    ///
+    pub fn components(self) -> impl Iterator<Item = (u32, &'static str)> {
+        self.arithmoi()
+            .map(|arithmos| (arithmos.arabic, arithmos.attic(Case::Upper)))
+    }
+
+    /// Iterates over the lowercase glyphs making up a ``GreekNumeral``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    let mut glyphs = answer.lowercase_components();
+    ///    assert_eq!(glyphs.next(), Some((40, "μ")));
+    ///    assert_eq!(glyphs.next(), Some((2, "β")));
+    ///    assert_eq!(glyphs.next(), None);
+    ///
+    pub fn lowercase_components(self) -> impl Iterator<Item = (u32, &'static str)> {
+        self.arithmoi()
+            .map(|arithmos| (arithmos.arabic, arithmos.attic(Case::Lower)))
+    }
+
+    /// Returns the place value of each glyph in a ``GreekNumeral`` with the
+    /// number of times it is written, from the highest place down.
+    ///
+    /// Every place has its own glyph for each digit, so no glyph repeats and
+    /// each count is 1; 0 (ZERO) has no places and returns an empty ``Vec``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!(answer.place_breakdown(), vec![(40, 1), (2, 1)]);
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn place_breakdown(self) -> Vec<(u32, u8)> {
+        self.arithmoi()
+            .map(|arithmos| (arithmos.arabic, 1))
+            .collect()
+    }
+
+    /// Counts the characters this ``GreekNumeral`` renders to, excluding the
+    /// keraia, without building a ``String``.
+    ///
+    /// Thousands glyphs count their ``͵`` marker as a separate character.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(1042)?;
+    ///    assert_eq!(answer.glyph_count(), 4);
+    ///
+    #[must_use]
+    pub fn glyph_count(self) -> usize {
+        if self.0 == 0 {
+            return 1;
+        }
+        self.arithmoi()
+            .map(|arithmos| arithmos.u_attic.chars().count())
+            .sum()
+    }
+
+    /// Returns the length in bytes of this ``GreekNumeral`` rendered in the
+    /// given ``Case`` with its keraia, without building a ``String``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!(answer.byte_len(Case::Upper), answer.to_uppercase().len());
+    ///
+    #[must_use]
+    pub fn byte_len(self, case: Case) -> usize {
+        let glyphs = if self.0 == 0 {
+            ZERO.len()
+        } else {
+            self.arithmoi()
+                .map(|arithmos| arithmos.attic(case).len())
+                .sum()
+        };
+        glyphs + '\''.len_utf8()
+    }
+
+    /// Yields the ``ARITHMOI`` entry for each non-zero decimal place, from
+    /// the highest place down.
+    fn arithmoi(self) -> impl Iterator<Item = &'static Arabic2GreekStruct<'static>> {
+        let value = self.0;
+        (0..PLACES).rev().filter_map(move |place| {
+            let digit = value / 10_u32.pow(place as u32) % 10;
+            (digit != 0).then(|| table::lookup(place, digit))
+        })
+    }
+
+    /// Converts a ``GreekNumeral`` to an acrophonic (Attic) string.
+    ///
+    /// The acrophonic system is additive and predates the alphabetic one.
+    /// The signs for 5, 50, 500, 5,000 and 50,000 are the pi ligatures from
+    /// the Ancient Greek Numbers block. No keraia is appended.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!("ΔΔΔΔΙΙ", answer.to_acrophonic());
+    ///
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn to_acrophonic(self) -> String {
+        let mut out = String::new();
+        let mut n = self.0;
+        if n == 0 {
+            out.push_str(ZERO);
+        } else {
+            for acrophonic in ACROPHONIC.iter() {
+                while n >= acrophonic.arabic {
+                    n -= acrophonic.arabic;
+                    out.push_str(acrophonic.attic);
+                }
+            }
+        }
+        out
+    }
+}
+
+impl Default for GreekNumeral {
+    /// Creates the ``GreekNumeral`` for 0 (ZERO), the value of ``MIN``.
+    fn default() -> Self {
+        Self(MIN)
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::Zero for GreekNumeral {
+    /// Creates the ``GreekNumeral`` for 0 (ZERO).
+    fn zero() -> Self {
+        Self(MIN)
+    }
+
+    /// Returns whether this ``GreekNumeral`` is 0 (ZERO).
+    fn is_zero(&self) -> bool {
+        self.0 == MIN
+    }
+}
+
+#[cfg(feature = "num-traits")]
+impl num_traits::Bounded for GreekNumeral {
+    /// Returns ``GreekNumeral::MIN``.
+    fn min_value() -> Self {
+        Self::MIN
+    }
+
+    /// Returns ``GreekNumeral::MAX``.
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand::distr::Distribution<GreekNumeral> for rand::distr::StandardUniform {
+    /// Samples a ``GreekNumeral`` uniformly from ``MIN`` to ``MAX``.
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> GreekNumeral {
+        GreekNumeral(rng.random_range(MIN..=MAX))
+    }
+}
+
+impl Add for GreekNumeral {
+    type Output = Self;
+
+    /// Adds two ``GreekNumeral`` values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sum is greater than ``MAX``. Use ``checked_add`` or
+    /// ``saturating_add`` to handle this case without panicking.
+    fn add(self, rhs: Self) -> Self {
+        self.checked_add(rhs)
+            .expect("attempt to add GreekNumeral values past MAX")
+    }
+}
+
+impl Sub for GreekNumeral {
+    type Output = Self;
+
+    /// Subtracts two ``GreekNumeral`` values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the difference is less than ``MIN``. Use ``checked_sub`` or
+    /// ``saturating_sub`` to handle this case without panicking.
+    fn sub(self, rhs: Self) -> Self {
+        self.checked_sub(rhs)
+            .expect("attempt to subtract GreekNumeral values below MIN")
+    }
+}
+
+impl AddAssign for GreekNumeral {
+    /// Adds a ``GreekNumeral`` in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sum is greater than ``MAX``, like ``Add``.
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for GreekNumeral {
+    /// Subtracts a ``GreekNumeral`` in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the difference is less than ``MIN``, like ``Sub``.
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sum for GreekNumeral {
+    /// Totals an iterator of ``GreekNumeral`` values.
+    ///
+    /// Unlike ``Add``, the total saturates at ``MAX`` instead of panicking.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self(MIN), Self::saturating_add)
+    }
+}
+
+impl<'a> Sum<&'a GreekNumeral> for GreekNumeral {
+    /// Totals an iterator of ``&GreekNumeral`` values.
+    ///
+    /// Unlike ``Add``, the total saturates at ``MAX`` instead of panicking.
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl FromIterator<GreekNumeral> for Result<GreekNumeral, OutOfRangeError> {
+    /// Totals an iterator of ``GreekNumeral`` values.
+    ///
+    /// Returns ``OutOfRangeError`` if the total exceeds ``MAX``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let numerals = [GreekNumeral::new(40)?, GreekNumeral::new(2)?];
+    ///    let total: Result<GreekNumeral, _> = numerals.into_iter().collect();
+    ///    assert_eq!(total, GreekNumeral::new(42));
+    ///
+    fn from_iter<I: IntoIterator<Item = GreekNumeral>>(iter: I) -> Self {
+        iter.into_iter()
+            .try_fold(GreekNumeral(MIN), |total, numeral| {
+                GreekNumeral::new(total.0 + numeral.0)
+            })
+    }
+}
+
+/// Extension methods for converting an iterator of ``u32`` values into
+/// ``GreekNumeral`` values
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    let numerals: Result<Vec<GreekNumeral>, _> = (1..=10).greek_numerals().collect();
+///    assert_eq!(numerals?.len(), 10);
+///
+pub trait GreekNumeralIterExt: Iterator<Item = u32> + Sized {
+    /// Lazily converts each value with ``GreekNumeral::new``.
+    fn greek_numerals(self) -> impl Iterator<Item = Result<GreekNumeral>> {
+        self.map(GreekNumeral::new)
+    }
+}
+
+impl<I: Iterator<Item = u32>> GreekNumeralIterExt for I {}
+
+impl PartialEq<u32> for GreekNumeral {
+    fn eq(&self, other: &u32) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<GreekNumeral> for u32 {
+    fn eq(&self, other: &GreekNumeral) -> bool {
+        *self == other.0
+    }
+}
+
+impl PartialOrd<u32> for GreekNumeral {
+    fn partial_cmp(&self, other: &u32) -> Option<Ordering> {
+        self.0.partial_cmp(other)
+    }
+}
+
+impl PartialOrd<GreekNumeral> for u32 {
+    fn partial_cmp(&self, other: &GreekNumeral) -> Option<Ordering> {
+        self.partial_cmp(&other.0)
+    }
+}
+
+impl fmt::Display for GreekNumeral {
+    /// Converts a ``GreekNumeral`` to an uppercase string.
+    ///
+    /// The alternate flag (``{:#}``) leaves out the trailing keraia. Width,
+    /// fill and alignment are honoured, counting width in ``char``s rather
+    /// than bytes; the default alignment is left, as for strings.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!("ΜΒ'", answer.to_string());
+    ///    assert_eq!("ΜΒ", format!("{:#}", answer));
+    ///    assert_eq!("  ΜΒ'", format!("{:>5}", answer));
+    ///
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let opts = Format {
+            with_keraia: !f.alternate(),
+            ..Format::default()
+        };
+        self.display(f, "", opts)
+    }
+}
+
+impl fmt::Debug for GreekNumeral {
+    /// Shows both the value and the rendered glyphs of a ``GreekNumeral``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+    ///    assert_eq!(r#"GreekNumeral(42 = "ΜΒ'")"#, format!("{:?}", answer));
+    ///
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "GreekNumeral({} = \"{self}\")", self.0)
+    }
+}
+
+/// Wrapper that displays a ``GreekNumeral`` in lowercase
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    let answer: GreekNumeral = GreekNumeral::new(42)?;
+///    assert_eq!("μβ'", format!("{}", LowerGreek(answer)));
+///
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct LowerGreek(pub GreekNumeral);
+
+impl fmt::Display for LowerGreek {
+    /// Converts the wrapped ``GreekNumeral`` to a lowercase string.
+    ///
+    /// The alternate flag (``{:#}``) leaves out the trailing keraia.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let opts = Format {
+            case: Case::Lower,
+            with_keraia: !f.alternate(),
+            ..Format::default()
+        };
+        self.0.display(f, "", opts)
+    }
+}
+
+/// A ``GreekNumeral`` with a sign, for displaying negative amounts
+///
+/// The magnitude is limited to ``MIN..=MAX`` like any ``GreekNumeral``.
+/// Zero is never negative, so there is a single ``SignedGreek`` for it.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    let debit: SignedGreek = SignedGreek::new(-42)?;
+///    assert_eq!("−ΜΒ'", debit.to_string());
+///
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SignedGreek {
+    numeral: GreekNumeral,
+    negative: bool,
+}
+
+impl SignedGreek {
+    /// Creates a ``SignedGreek`` from an ``i32``.
+    ///
+    /// Returns ``OutOfRangeError`` if the magnitude is greater than ``MAX``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let credit: SignedGreek = SignedGreek::new(42)?;
+    ///    assert!(!credit.is_negative());
+    ///
+    pub const fn new(value: i32) -> Result<Self> {
+        match GreekNumeral::new(value.unsigned_abs()) {
+            Ok(numeral) => Ok(Self {
+                numeral,
+                negative: value < 0,
+            }),
+            Err(_) => Err(OutOfRangeError::new(value as i128, MAX)),
+        }
+    }
+
+    /// Returns the magnitude as a ``GreekNumeral``.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let debit: SignedGreek = SignedGreek::new(-42)?;
+    ///    assert_eq!(debit.numeral(), GreekNumeral::new(42)?);
+    ///
+    #[must_use]
+    pub const fn numeral(self) -> GreekNumeral {
+        self.numeral
+    }
+
+    /// Returns whether the value is below zero.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let debit: SignedGreek = SignedGreek::new(-42)?;
+    ///    assert!(debit.is_negative());
+    ///
+    #[must_use]
+    pub const fn is_negative(self) -> bool {
+        self.negative
+    }
+}
+
+impl fmt::Display for SignedGreek {
+    /// Converts a ``SignedGreek`` to an uppercase string, prefixed with
+    /// MINUS SIGN (U+2212) if negative.
+    ///
+    /// The alternate flag (``{:#}``) leaves out the trailing keraia.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let opts = Format {
+            with_keraia: !f.alternate(),
+            ..Format::default()
+        };
+        let sign = if self.negative { "\u{2212}" } else { "" };
+        self.numeral.display(f, sign, opts)
+    }
+}
+
+// Every ``u8`` is in range, so the conversion cannot fail.
+const _: () = assert!(u8::MAX as u32 <= MAX);
+
+impl From<u8> for GreekNumeral {
+    /// Creates a ``GreekNumeral`` from an ``u8``.
+    ///
+    /// ``TryFrom<u8>`` is still available, with ``Infallible`` as its error.
+    fn from(value: u8) -> Self {
+        Self(u32::from(value))
+    }
+}
+
+impl TryFrom<u16> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``u16``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u16) -> Result<Self, OutOfRangeError> {
+        Self::new(u32::from(value))
+    }
+}
+
+impl TryFrom<u32> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``u32``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u32) -> Result<Self, OutOfRangeError> {
+        Self::new(value)
+    }
+}
+
+impl TryFrom<u64> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``u64``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u64) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError::new(i128::from(value), MAX)), Self::new)
+    }
+}
+
+impl TryFrom<u128> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``u128``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: u128) -> Result<Self, OutOfRangeError> {
+        let reported = i128::try_from(value).unwrap_or(i128::MAX);
+        u32::try_from(value).map_or(Err(OutOfRangeError::new(reported, MAX)), Self::new)
+    }
+}
+
+impl TryFrom<usize> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``usize``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: usize) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError::new(value as i128, MAX)), Self::new)
+    }
+}
+
+impl TryFrom<i8> for GreekNumeral {
+    type Error = OutOfRangeError;
 
     /// Creates a ``GreekNumeral`` from an ``i8``.
     ///
     /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
     fn try_from(value: i8) -> Result<Self, OutOfRangeError> {
-        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+        u32::try_from(value).map_or(Err(OutOfRangeError::new(i128::from(value), MAX)), Self::new)
     }
 }
 
@@ -520,7 +2135,7 @@ impl TryFrom<i16> for GreekNumeral {
     ///
     /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
     fn try_from(value: i16) -> Result<Self, OutOfRangeError> {
-        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+        u32::try_from(value).map_or(Err(OutOfRangeError::new(i128::from(value), MAX)), Self::new)
     }
 }
 
@@ -531,53 +2146,428 @@ impl TryFrom<i32> for GreekNumeral {
     ///
     /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
     fn try_from(value: i32) -> Result<Self, OutOfRangeError> {
-        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+        u32::try_from(value).map_or(Err(OutOfRangeError::new(i128::from(value), MAX)), Self::new)
+    }
+}
+
+impl TryFrom<i64> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``i64``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i64) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError::new(i128::from(value), MAX)), Self::new)
+    }
+}
+
+impl TryFrom<i128> for GreekNumeral {
+    type Error = OutOfRangeError;
+
+    /// Creates a ``GreekNumeral`` from an ``i128``.
+    ///
+    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
+    fn try_from(value: i128) -> Result<Self, OutOfRangeError> {
+        u32::try_from(value).map_or(Err(OutOfRangeError::new(value, MAX)), Self::new)
+    }
+}
+
+impl From<GreekNumeral> for u32 {
+    /// Converts a ``GreekNumeral`` to a ``u32``.
+    fn from(numeral: GreekNumeral) -> Self {
+        numeral.0
+    }
+}
+
+impl From<GreekNumeral> for u64 {
+    /// Converts a ``GreekNumeral`` to a ``u64``.
+    fn from(numeral: GreekNumeral) -> Self {
+        u64::from(numeral.0)
+    }
+}
+
+impl From<GreekNumeral> for u128 {
+    /// Converts a ``GreekNumeral`` to a ``u128``.
+    fn from(numeral: GreekNumeral) -> Self {
+        u128::from(numeral.0)
+    }
+}
+
+impl From<GreekNumeral> for i64 {
+    /// Converts a ``GreekNumeral`` to a ``i64``.
+    fn from(numeral: GreekNumeral) -> Self {
+        i64::from(numeral.0)
+    }
+}
+
+impl From<GreekNumeral> for i128 {
+    /// Converts a ``GreekNumeral`` to a ``i128``.
+    fn from(numeral: GreekNumeral) -> Self {
+        i128::from(numeral.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_greek_numeral_new() {
+        assert_eq!(GreekNumeral::new(0), Ok(GreekNumeral(0_u32)));
+        assert_eq!(GreekNumeral::new(1), Ok(GreekNumeral(1_u32)));
+        assert_eq!(GreekNumeral::new(1_u8.into()), Ok(GreekNumeral(1_u32)));
+        assert_eq!(GreekNumeral::new(1_u32), Ok(GreekNumeral(1_u32)));
+        assert_eq!(GreekNumeral::new(42), Ok(GreekNumeral(42_u32)));
+        assert_eq!(GreekNumeral::new(616), Ok(GreekNumeral(616_u32)));
+        assert_eq!(GreekNumeral::new(9999), Ok(GreekNumeral(9999_u32)));
+        assert_eq!(GreekNumeral::new(MAX), Ok(GreekNumeral(MAX)));
+        assert!(matches!(
+            GreekNumeral::new(MAX + 1),
+            Err(OutOfRangeError { .. })
+        ));
+        assert!(matches!(
+            GreekNumeral::new(u32::MAX),
+            Err(OutOfRangeError { .. })
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "large-range")]
+    fn test_greek_numeral_large_range_boundary() {
+        assert_eq!(MAX, 999_999);
+        assert_eq!(GreekNumeral::new(10_000), Ok(GreekNumeral(10_000_u32)));
+        assert_eq!(GreekNumeral::new(49_999), Ok(GreekNumeral(49_999_u32)));
+        assert_eq!(GreekNumeral::new(99_999), Ok(GreekNumeral(99_999_u32)));
+        assert_eq!(GreekNumeral::new(999_999), Ok(GreekNumeral(999_999_u32)));
+        assert!(matches!(
+            GreekNumeral::new(1_000_000),
+            Err(OutOfRangeError { .. })
+        ));
+        assert_eq!(GreekNumeral::new(12_345).unwrap().digits(), [2, 3, 4, 5]);
+        assert_eq!(GreekNumeral::new(10_000).unwrap().place_count(), 5);
+    }
+
+    #[test]
+    fn test_greek_macro() {
+        const ANSWER: GreekNumeral = greek!(42);
+        assert_eq!(ANSWER, GreekNumeral(42));
+        assert_eq!(greek!(0), GreekNumeral::MIN);
+        assert_eq!(greek!(MAX), GreekNumeral::MAX);
+        assert_eq!(greek!(6 * 100 + 16).as_u32(), 616);
+    }
+
+    #[test]
+    #[cfg(feature = "num-traits")]
+    fn test_num_traits() {
+        use num_traits::{Bounded, Zero};
+
+        assert_eq!(GreekNumeral::zero().as_u32(), 0);
+        assert!(GreekNumeral::zero().is_zero());
+        assert!(!GreekNumeral::new(42).unwrap().is_zero());
+        assert_eq!(GreekNumeral::min_value().as_u32(), MIN);
+        assert_eq!(GreekNumeral::max_value().as_u32(), MAX);
+        assert_eq!(
+            GreekNumeral::zero() + GreekNumeral::new(42).unwrap(),
+            GreekNumeral(42)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_rand() {
+        use rand::rngs::SmallRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = SmallRng::seed_from_u64(42);
+        let sampled: [GreekNumeral; 1000] = core::array::from_fn(|_| rng.random());
+        assert!(
+            sampled
+                .iter()
+                .all(|num| (MIN..=MAX).contains(&num.as_u32()))
+        );
+        assert!(sampled.windows(2).any(|pair| pair[0] != pair[1]));
+        let mut again = SmallRng::seed_from_u64(42);
+        assert_eq!(sampled[0], again.random::<GreekNumeral>());
+    }
+
+    #[test]
+    fn test_greek_numeral_succ_pred() {
+        let num = GreekNumeral::new(42).unwrap();
+        assert_eq!(num.succ(), Some(GreekNumeral(43)));
+        assert_eq!(num.pred(), Some(GreekNumeral(41)));
+        assert_eq!(GreekNumeral::new(MAX).unwrap().succ(), None);
+        assert_eq!(
+            GreekNumeral::new(MAX).unwrap().pred(),
+            Some(GreekNumeral(MAX - 1))
+        );
+        assert_eq!(GreekNumeral::new(0).unwrap().pred(), None);
+        assert_eq!(GreekNumeral::new(0).unwrap().succ(), Some(GreekNumeral(1)));
+    }
+
+    #[test]
+    fn test_greek_numeral_checked() {
+        let forty = GreekNumeral::new(40).unwrap();
+        let two = GreekNumeral::new(2).unwrap();
+        let max = GreekNumeral::new(MAX).unwrap();
+        assert_eq!(forty.checked_add(two), Some(GreekNumeral(42)));
+        assert_eq!(forty.checked_sub(two), Some(GreekNumeral(38)));
+        assert_eq!(max.checked_add(two), None);
+        assert_eq!(two.checked_sub(forty), None);
+    }
+
+    #[test]
+    fn test_greek_numeral_checked_mul() {
+        let thousand = GreekNumeral::new(1000).unwrap();
+        assert_eq!(thousand.checked_mul(9), Some(GreekNumeral(9000)));
+        assert_eq!(thousand.checked_mul(0), Some(GreekNumeral(0)));
+        assert_eq!(GreekNumeral::MAX.checked_mul(1), Some(GreekNumeral::MAX));
+        assert_eq!(GreekNumeral::MAX.checked_mul(2), None);
+        assert_eq!(thousand.checked_mul(MAX / 1000 + 1), None);
+        assert_eq!(thousand.checked_mul(u32::MAX), None);
+    }
+
+    #[test]
+    fn test_greek_numeral_add_sub_assign() {
+        let one = GreekNumeral::new(1).unwrap();
+        let mut counter = GreekNumeral::default();
+        for _ in 0..42 {
+            counter += one;
+        }
+        assert_eq!(counter, GreekNumeral(42));
+        for _ in 0..2 {
+            counter -= one;
+        }
+        assert_eq!(counter, GreekNumeral(40));
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add GreekNumeral values past MAX")]
+    fn test_greek_numeral_add_assign_overflow() {
+        let mut max = GreekNumeral::MAX;
+        max += GreekNumeral::new(1).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to subtract GreekNumeral values below MIN")]
+    fn test_greek_numeral_sub_assign_underflow() {
+        let mut min = GreekNumeral::MIN;
+        min -= GreekNumeral::new(1).unwrap();
+    }
+
+    #[test]
+    fn test_greek_numeral_add_sub() {
+        let forty = GreekNumeral::new(40).unwrap();
+        let two = GreekNumeral::new(2).unwrap();
+        assert_eq!(forty + two, GreekNumeral(42));
+        assert_eq!(forty - two, GreekNumeral(38));
+    }
+
+    #[test]
+    #[should_panic(expected = "past MAX")]
+    fn test_greek_numeral_add_overflow() {
+        let _ = GreekNumeral::new(MAX).unwrap() + GreekNumeral::new(1).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "below MIN")]
+    fn test_greek_numeral_sub_overflow() {
+        let _ = GreekNumeral::new(1).unwrap() - GreekNumeral::new(2).unwrap();
+    }
+
+    #[test]
+    fn test_greek_numeral_sum() {
+        let numerals = [10, 20, 12].map(|i| GreekNumeral::new(i).unwrap());
+        assert_eq!(numerals.iter().sum::<GreekNumeral>(), GreekNumeral(42));
+        assert_eq!(numerals.into_iter().sum::<GreekNumeral>(), GreekNumeral(42));
+        let numerals = [MAX, 1].map(|i| GreekNumeral::new(i).unwrap());
+        assert_eq!(numerals.iter().sum::<GreekNumeral>(), GreekNumeral(MAX));
+        assert_eq!(
+            core::iter::empty::<GreekNumeral>().sum::<GreekNumeral>(),
+            GreekNumeral(0)
+        );
+    }
+
+    #[test]
+    fn test_greek_numeral_saturating() {
+        let forty = GreekNumeral::new(40).unwrap();
+        let two = GreekNumeral::new(2).unwrap();
+        let max = GreekNumeral::new(MAX).unwrap();
+        assert_eq!(forty.saturating_add(two), GreekNumeral(42));
+        assert_eq!(forty.saturating_sub(two), GreekNumeral(38));
+        assert_eq!(max.saturating_add(two), GreekNumeral(MAX));
+        assert_eq!(max.saturating_add(max), GreekNumeral(MAX));
+        assert_eq!(two.saturating_sub(forty), GreekNumeral(MIN));
+        assert_eq!(GreekNumeral(MIN).saturating_sub(max), GreekNumeral(MIN));
+    }
+
+    #[test]
+    fn test_greek_numeral_wrapping_add() {
+        let forty = GreekNumeral::new(40).unwrap();
+        let one = GreekNumeral::new(1).unwrap();
+        let max = GreekNumeral::new(MAX).unwrap();
+        assert_eq!(forty.wrapping_add(GreekNumeral(2)), GreekNumeral(42));
+        assert_eq!(max.wrapping_add(one), GreekNumeral(MIN));
+        assert_eq!(max.wrapping_add(forty), GreekNumeral(39));
+        assert_eq!(max.wrapping_add(max), GreekNumeral(MAX - 1));
+        assert_eq!(GreekNumeral(MIN).wrapping_add(max), max);
+    }
+
+    #[test]
+    fn test_greek_numeral_cmp_u32() {
+        let num = GreekNumeral::new(42).unwrap();
+        assert_eq!(num, 42);
+        assert_eq!(42, num);
+        assert_ne!(num, 43);
+        assert!(num < 43);
+        assert!(num > 41);
+        assert!(41 < num);
+        assert!(43 >= num);
+        assert_eq!(num.partial_cmp(&42), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn test_greek_numeral_new_clamped() {
+        assert_eq!(GreekNumeral::new_clamped(MIN), GreekNumeral(MIN));
+        assert_eq!(GreekNumeral::new_clamped(42), GreekNumeral(42));
+        assert_eq!(GreekNumeral::new_clamped(MAX), GreekNumeral(MAX));
+        assert_eq!(GreekNumeral::new_clamped(MAX + 1), GreekNumeral(MAX));
+        assert_eq!(GreekNumeral::new_clamped(u32::MAX), GreekNumeral(MAX));
+    }
+
+    #[test]
+    fn test_greek_numeral_from_digits() {
+        assert_eq!(GreekNumeral::from_digits(0, 0, 4, 2), Ok(GreekNumeral(42)));
+        assert_eq!(
+            GreekNumeral::from_digits(1, 9, 8, 4),
+            Ok(GreekNumeral(1984))
+        );
+        assert_eq!(GreekNumeral::from_digits(0, 0, 0, 0), Ok(GreekNumeral(0)));
+        assert_eq!(
+            GreekNumeral::from_digits(9, 9, 9, 9),
+            Ok(GreekNumeral(9999))
+        );
+        assert!(matches!(
+            GreekNumeral::from_digits(10, 0, 0, 0),
+            Err(OutOfRangeError { .. })
+        ));
+        assert!(matches!(
+            GreekNumeral::from_digits(0, 10, 0, 0),
+            Err(OutOfRangeError { .. })
+        ));
+        assert!(matches!(
+            GreekNumeral::from_digits(0, 0, 10, 0),
+            Err(OutOfRangeError { .. })
+        ));
+        assert!(matches!(
+            GreekNumeral::from_digits(0, 0, 0, 10),
+            Err(OutOfRangeError { .. })
+        ));
+    }
+
+    #[test]
+    fn test_greek_numeral_digits() {
+        assert_eq!(GreekNumeral::new(0).unwrap().digits(), [0, 0, 0, 0]);
+        assert_eq!(GreekNumeral::new(42).unwrap().digits(), [0, 0, 4, 2]);
+        assert_eq!(GreekNumeral::new(616).unwrap().digits(), [0, 6, 1, 6]);
+        assert_eq!(GreekNumeral::new(1984).unwrap().digits(), [1, 9, 8, 4]);
+        assert_eq!(GreekNumeral::new(9999).unwrap().digits(), [9, 9, 9, 9]);
+        for i in 0..=9999 {
+            let [t, h, d, u] = GreekNumeral::new(i).unwrap().digits();
+            assert_eq!(GreekNumeral::from_digits(t, h, d, u), Ok(GreekNumeral(i)));
+        }
     }
-}
 
-impl TryFrom<i64> for GreekNumeral {
-    type Error = OutOfRangeError;
+    const _: () = assert!(GreekNumeral(0).place_count() == 0);
+    const _: () = assert!(GreekNumeral(9).place_count() == 1);
+    const _: () = assert!(GreekNumeral(9999).place_count() == 4);
+    const _: () = assert!(GreekNumeral(MAX).place_count() == PLACES as u32);
 
-    /// Creates a ``GreekNumeral`` from an ``i64``.
-    ///
-    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
-    fn try_from(value: i64) -> Result<Self, OutOfRangeError> {
-        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    #[test]
+    fn test_greek_numeral_divisibility() {
+        assert!(GreekNumeral(42).is_even());
+        assert!(!GreekNumeral(43).is_even());
+        assert!(GreekNumeral(0).is_even());
+        assert!(GreekNumeral(42).is_divisible_by(3));
+        assert!(GreekNumeral(42).is_divisible_by(7));
+        assert!(!GreekNumeral(43).is_divisible_by(3));
+        assert!(!GreekNumeral(50).is_divisible_by(7));
+        assert!(GreekNumeral(616).is_divisible_by(7));
+        assert!(GreekNumeral(0).is_divisible_by(0));
+        assert!(!GreekNumeral(42).is_divisible_by(0));
     }
-}
 
-impl TryFrom<i128> for GreekNumeral {
-    type Error = OutOfRangeError;
+    #[test]
+    fn test_greek_numeral_next_decade() {
+        assert_eq!(GreekNumeral(42).next_decade(), Some(GreekNumeral(100)));
+        assert_eq!(GreekNumeral(999).next_decade(), Some(GreekNumeral(1000)));
+        #[cfg(feature = "large-range")]
+        assert_eq!(GreekNumeral(1000).next_decade(), Some(GreekNumeral(10_000)));
+        assert_eq!(GreekNumeral(0).next_decade(), Some(GreekNumeral(1)));
+        assert_eq!(GreekNumeral(9).next_decade(), Some(GreekNumeral(10)));
+        let top = 10_u32.pow(PLACES as u32 - 1);
+        assert_eq!(GreekNumeral(top).next_decade(), None);
+        assert_eq!(GreekNumeral::MAX.next_decade(), None);
+    }
 
-    /// Creates a ``GreekNumeral`` from an ``i128``.
-    ///
-    /// Returns ``GreekNumeral`` or ``OutOfRangeError``.
-    fn try_from(value: i128) -> Result<Self, OutOfRangeError> {
-        u32::try_from(value).map_or(Err(OutOfRangeError), Self::new)
+    #[test]
+    fn test_greek_numeral_leading_place() {
+        assert_eq!(GreekNumeral::new(0).unwrap().leading_place(), 0);
+        assert_eq!(GreekNumeral::new(7).unwrap().leading_place(), 7);
+        assert_eq!(GreekNumeral::new(42).unwrap().leading_place(), 40);
+        assert_eq!(GreekNumeral::new(616).unwrap().leading_place(), 600);
+        assert_eq!(GreekNumeral::new(2456).unwrap().leading_place(), 2000);
+        assert_eq!(GreekNumeral::new(9999).unwrap().leading_place(), 9000);
+        assert_eq!(GreekNumeral::MAX.leading_place(), ARITHMOI[0].arabic);
+        for i in 1..=MAX {
+            let num = GreekNumeral::new(i).unwrap();
+            let leading = num.components().next().map(|(value, _)| value);
+            assert_eq!(Some(num.leading_place()), leading);
+        }
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    #[test]
+    fn test_greek_numeral_all() {
+        assert_eq!(GreekNumeral::all().count(), (MAX - MIN + 1) as usize);
+        assert_eq!(GreekNumeral::all().len(), (MAX - MIN + 1) as usize);
+        assert_eq!(GreekNumeral::all().next(), Some(GreekNumeral::MIN));
+        assert_eq!(GreekNumeral::all().next_back(), Some(GreekNumeral::MAX));
+        assert!(
+            GreekNumeral::all()
+                .zip(MIN..)
+                .all(|(num, i)| num.as_u32() == i)
+        );
+    }
 
     #[test]
-    fn test_greek_numeral_new() {
-        assert_eq!(GreekNumeral::new(0), Ok(GreekNumeral(0_u32)));
-        assert_eq!(GreekNumeral::new(1), Ok(GreekNumeral(1_u32)));
-        assert_eq!(GreekNumeral::new(1_u8.into()), Ok(GreekNumeral(1_u32)));
-        assert_eq!(GreekNumeral::new(1_u32), Ok(GreekNumeral(1_u32)));
-        assert_eq!(GreekNumeral::new(42), Ok(GreekNumeral(42_u32)));
-        assert_eq!(GreekNumeral::new(616), Ok(GreekNumeral(616_u32)));
-        assert_eq!(GreekNumeral::new(49_999), Ok(GreekNumeral(49_999_u32)));
-        assert_eq!(GreekNumeral::new(99_999), Ok(GreekNumeral(99_999_u32)));
-        assert_eq!(GreekNumeral::new(999_999), Ok(GreekNumeral(999_999_u32)));
-        assert_eq!(GreekNumeral::new(MAX), Ok(GreekNumeral(999_999_u32)));
-        assert!(matches!(GreekNumeral::new(1_000_000), Err(OutOfRangeError)));
-        assert!(matches!(GreekNumeral::new(u32::MAX), Err(OutOfRangeError)));
+    fn test_greek_numeral_min_max() {
+        assert_eq!(GreekNumeral::MIN.as_u32(), MIN);
+        assert_eq!(GreekNumeral::MAX.as_u32(), MAX);
+        assert_eq!(GreekNumeral::MIN, GreekNumeral::default());
+        assert_eq!(GreekNumeral::MAX.succ(), None);
+    }
+
+    #[test]
+    fn test_into_integers() {
+        let num = GreekNumeral::new(MAX).unwrap();
+        assert_eq!(u32::from(num), MAX);
+        assert_eq!(u64::from(num), u64::from(MAX));
+        assert_eq!(u128::from(num), u128::from(MAX));
+        assert_eq!(i64::from(num), i64::from(MAX));
+        assert_eq!(i128::from(num), i128::from(MAX));
+        let answer: u64 = GreekNumeral::new(42).unwrap().into();
+        assert_eq!(answer, 42);
+        let zero: i128 = GreekNumeral::new(0).unwrap().into();
+        assert_eq!(zero, 0);
+        for i in [0, 1, 42, 616, 1984, MAX] {
+            let num = GreekNumeral::new(i).unwrap();
+            assert_eq!(GreekNumeral::try_from(u64::from(num)), Ok(num));
+            assert_eq!(GreekNumeral::try_from(i128::from(num)), Ok(num));
+        }
     }
 
     #[test]
+    // ``TryFrom<u8>`` comes from ``From<u8>`` but must keep working.
+    #[allow(clippy::unnecessary_fallible_conversions)]
     fn test_try_from_one() {
         assert_eq!(GreekNumeral::try_from(1_u8), Ok(GreekNumeral(1_u32)));
         assert_eq!(GreekNumeral::try_from(1_u16), Ok(GreekNumeral(1_u32)));
@@ -592,22 +2582,1066 @@ mod test {
         assert_eq!(GreekNumeral::try_from(1_i128), Ok(GreekNumeral(1_u32)));
     }
 
+    #[test]
+    fn test_numeral_count() {
+        assert_eq!(ARITHMOI.len(), NUMERAL_COUNT);
+        assert_eq!(NUMERAL_COUNT, (MAX + 1).ilog10() as usize * 9);
+        #[cfg(feature = "large-range")]
+        assert_eq!(NUMERAL_COUNT, 54);
+        #[cfg(not(feature = "large-range"))]
+        assert_eq!(NUMERAL_COUNT, 36);
+    }
+
+    #[test]
+    fn test_glyph_for() {
+        assert_eq!(glyph_for(40, Case::Upper), Some("Μ"));
+        assert_eq!(glyph_for(40, Case::Lower), Some("μ"));
+        assert_eq!(glyph_for(400, Case::Upper), Some("Υ"));
+        assert_eq!(glyph_for(4000, Case::Upper), Some("͵Δ"));
+        assert_eq!(glyph_for(4000, Case::Lower), Some("͵δ"));
+        assert_eq!(glyph_for(1, Case::Upper), Some("Α"));
+        assert_eq!(glyph_for(42, Case::Upper), None);
+        assert_eq!(glyph_for(0, Case::Upper), None);
+        assert_eq!(glyph_for(10_u32.pow(PLACES as u32), Case::Upper), None);
+        assert_eq!(glyph_for(u32::MAX, Case::Upper), None);
+        for arithmos in ARITHMOI.iter() {
+            assert_eq!(
+                glyph_for(arithmos.arabic, Case::Upper),
+                Some(arithmos.u_attic)
+            );
+        }
+    }
+
+    #[test]
+    fn test_greek_numeral_render_into() {
+        let mut buf = [0; MAX_GLYPH_BYTES];
+        let max = GreekNumeral::MAX;
+        assert_eq!(
+            max.render_into(&mut buf, Case::Upper).unwrap().len(),
+            max.byte_len(Case::Upper)
+        );
+        assert!(max.render_into(&mut buf, Case::Lower).is_ok());
+        assert_eq!(
+            GreekNumeral(42).render_into(&mut buf, Case::Upper),
+            Ok("ΜΒ'")
+        );
+        assert_eq!(
+            GreekNumeral(42).render_into(&mut buf, Case::Lower),
+            Ok("μβ'")
+        );
+        assert_eq!(GreekNumeral(0).render_into(&mut buf, Case::Upper), Ok("𐆊'"));
+        assert_eq!(
+            GreekNumeral(42).render_into(&mut buf[..4], Case::Upper),
+            Err(fmt::Error)
+        );
+        assert_eq!(
+            GreekNumeral(42).render_into(&mut buf[..5], Case::Upper),
+            Ok("ΜΒ'")
+        );
+        let widest = GreekNumeral::all()
+            .flat_map(|num| [num.byte_len(Case::Upper), num.byte_len(Case::Lower)])
+            .max();
+        assert_eq!(widest, Some(MAX_GLYPH_BYTES));
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod std_test {
+    use super::*;
+
+    #[test]
+    #[cfg(not(feature = "large-range"))]
+    fn test_greek_numeral_small_range_boundary() {
+        assert_eq!(MAX, 9999);
+        assert_eq!(GreekNumeral::new(9999), Ok(GreekNumeral(9999_u32)));
+        assert!(matches!(
+            GreekNumeral::new(10_000),
+            Err(OutOfRangeError { .. })
+        ));
+        assert_eq!(GreekNumeral::new_clamped(999_999), GreekNumeral(9999));
+        assert_eq!(GreekNumeral(9999).to_string(), "͵ΘϠϘΘ'");
+    }
+
+    #[test]
+    fn test_greek_numeral_checked_from_f64() {
+        assert_eq!(GreekNumeral::checked_from_f64(41.6), Ok(GreekNumeral(42)));
+        assert_eq!(GreekNumeral::checked_from_f64(41.4), Ok(GreekNumeral(41)));
+        assert_eq!(GreekNumeral::checked_from_f64(0.5), Ok(GreekNumeral(1)));
+        assert_eq!(GreekNumeral::checked_from_f64(-0.4), Ok(GreekNumeral(0)));
+        assert_eq!(
+            GreekNumeral::checked_from_f64(f64::from(MAX)),
+            Ok(GreekNumeral::MAX)
+        );
+        let Err(FromFloatError::OutOfRange(err)) = GreekNumeral::checked_from_f64(-3.2) else {
+            panic!("-3.2 must be out of range");
+        };
+        assert_eq!(err.value, -3);
+        let err = GreekNumeral::checked_from_f64(f64::from(MAX) + 0.5);
+        assert_eq!(
+            err,
+            Err(FromFloatError::OutOfRange(OutOfRangeError::new(
+                i128::from(MAX + 1),
+                MAX
+            )))
+        );
+        let max = GreekNumeral::MAX.to_arabic_string();
+        let nan = GreekNumeral::checked_from_f64(f64::NAN).unwrap_err();
+        assert_eq!(nan, FromFloatError::NaN);
+        assert_eq!(
+            nan.to_string(),
+            format!("NaN is not a number; expected 0 to {max}")
+        );
+        for infinity in [f64::INFINITY, f64::NEG_INFINITY] {
+            let err = GreekNumeral::checked_from_f64(infinity).unwrap_err();
+            assert_eq!(err, FromFloatError::Infinite);
+            assert_eq!(
+                err.to_string(),
+                format!("infinity is not a finite number; expected 0 to {max}")
+            );
+        }
+        assert_eq!(
+            GreekNumeral::checked_from_f64(-3.2)
+                .unwrap_err()
+                .to_string(),
+            format!("-3 is out of range; expected 0 to {max}")
+        );
+    }
+
+    #[test]
+    fn test_greek_numeral_default() {
+        assert_eq!(GreekNumeral::default().as_u32(), 0);
+        assert_eq!(GreekNumeral::default().to_string(), "𐆊'");
+    }
+
+    #[test]
+    fn test_greek_numeral_collect() {
+        let numerals = vec![10, 20, 12]
+            .into_iter()
+            .map(|i| GreekNumeral::new(i).unwrap());
+        let total: Result<GreekNumeral, _> = numerals.collect();
+        assert_eq!(total, Ok(GreekNumeral(42)));
+        let numerals = vec![MAX, 1]
+            .into_iter()
+            .map(|i| GreekNumeral::new(i).unwrap());
+        let total: Result<GreekNumeral, _> = numerals.collect();
+        assert!(matches!(total, Err(OutOfRangeError { .. })));
+    }
+
+    #[test]
+    fn test_greek_numeral_to_arabic_string() {
+        assert_eq!(GreekNumeral::new(0).unwrap().to_arabic_string(), "0");
+        assert_eq!(GreekNumeral::new(42).unwrap().to_arabic_string(), "42");
+        assert_eq!(GreekNumeral::new(999).unwrap().to_arabic_string(), "999");
+        assert_eq!(GreekNumeral::new(1000).unwrap().to_arabic_string(), "1,000");
+        assert_eq!(GreekNumeral::new(9999).unwrap().to_arabic_string(), "9,999");
+        #[cfg(feature = "large-range")]
+        assert_eq!(
+            GreekNumeral::new(999_999).unwrap().to_arabic_string(),
+            "999,999"
+        );
+        for i in [0, 7, 42, 616, 1984, MAX] {
+            let num = GreekNumeral::new(i).unwrap();
+            assert_eq!(
+                GreekNumeral::from_arabic_string(&num.to_arabic_string()),
+                Ok(num)
+            );
+        }
+    }
+
+    #[test]
+    fn test_greek_numeral_has_thousands() {
+        assert!(!GreekNumeral::new(0).unwrap().has_thousands());
+        assert!(!GreekNumeral::new(999).unwrap().has_thousands());
+        assert!(GreekNumeral::new(1000).unwrap().has_thousands());
+        assert!(GreekNumeral::MAX.has_thousands());
+        for i in [0, 42, 999, 1000, 1984, MAX] {
+            let num = GreekNumeral::new(i).unwrap();
+            assert_eq!(num.has_thousands(), num.to_string().contains('͵'));
+        }
+    }
+
+    #[test]
+    fn test_greek_numeral_place_count() {
+        assert_eq!(GreekNumeral::new(0).unwrap().place_count(), 0);
+        assert_eq!(GreekNumeral::new(1).unwrap().place_count(), 1);
+        assert_eq!(GreekNumeral::new(9).unwrap().place_count(), 1);
+        assert_eq!(GreekNumeral::new(10).unwrap().place_count(), 2);
+        assert_eq!(GreekNumeral::new(9999).unwrap().place_count(), 4);
+        for i in 1..=MAX {
+            let num = GreekNumeral::new(i).unwrap();
+            assert_eq!(num.place_count() as usize, i.to_string().len());
+        }
+    }
+
+    #[test]
+    fn test_greek_numeral_slices() {
+        let numerals = GreekNumeral::try_from_slice(&[0, 1, 42, 9999]).unwrap();
+        assert_eq!(
+            numerals,
+            vec![
+                GreekNumeral(0),
+                GreekNumeral(1),
+                GreekNumeral(42),
+                GreekNumeral(9999)
+            ]
+        );
+        assert_eq!(
+            GreekNumeral::to_strings(&numerals),
+            vec!["𐆊'", "Α'", "ΜΒ'", "͵ΘϠϘΘ'"]
+        );
+        let err = GreekNumeral::try_from_slice(&[1, MAX + 1, u32::MAX]).unwrap_err();
+        assert_eq!(err.value, i128::from(MAX + 1));
+        assert_eq!(GreekNumeral::try_from_slice(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_greek_numeral_range() {
+        let numerals: Vec<String> = GreekNumeral::range(1, 5)
+            .map(GreekNumeral::to_uppercase)
+            .collect();
+        assert_eq!(numerals, vec!["Α'", "Β'", "Γ'", "Δ'"]);
+        assert_eq!(GreekNumeral::range(5, 1).count(), 0);
+        assert_eq!(GreekNumeral::range(MAX - 1, u32::MAX).count(), 2);
+        assert_eq!(GreekNumeral::range(MAX + 1, u32::MAX).count(), 0);
+    }
+
+    #[test]
+    fn test_out_of_range_error() {
+        let err = GreekNumeral::new(MAX + 1).unwrap_err();
+        assert_eq!(err.value, i128::from(MAX + 1));
+        assert_eq!(err.max, MAX);
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "{} is out of range; expected 0 to {}",
+                MAX + 1,
+                GreekNumeral::MAX.to_arabic_string()
+            )
+        );
+        let err = GreekNumeral::try_from(-5_i32).unwrap_err();
+        assert_eq!(err.value, -5);
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "-5 is out of range; expected 0 to {}",
+                GreekNumeral::MAX.to_arabic_string()
+            )
+        );
+        #[cfg(feature = "large-range")]
+        assert!(err.to_string().ends_with("999,999"));
+        #[cfg(not(feature = "large-range"))]
+        assert!(err.to_string().ends_with("9,999"));
+        let err = GreekNumeral::try_from(u128::MAX).unwrap_err();
+        assert_eq!(err.value, i128::MAX);
+        let err = GreekNumeral::from_digits(0, 12, 0, 0).unwrap_err();
+        assert_eq!((err.value, err.max), (12, 9));
+        assert_eq!(err.to_string(), "12 is out of range; expected 0 to 9");
+    }
+
+    #[test]
+    fn test_out_of_range_error_boxed() {
+        let err: Box<dyn core::error::Error> = Box::new(GreekNumeral::new(MAX + 1).unwrap_err());
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "{} is out of range; expected 0 to {}",
+                MAX + 1,
+                GreekNumeral::MAX.to_arabic_string()
+            )
+        );
+        let result: Result<GreekNumeral, Box<dyn core::error::Error>> =
+            GreekNumeral::new(MAX + 1).map_err(Into::into);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_u8() {
+        assert_eq!(GreekNumeral::from(0_u8), GreekNumeral(0));
+        assert_eq!(GreekNumeral::from(42_u8).to_string(), "ΜΒ'");
+        assert_eq!(GreekNumeral::from(u8::MAX), GreekNumeral(255));
+        let num: GreekNumeral = 255_u8.into();
+        assert_eq!(num.as_u32(), 255);
+        assert_eq!(
+            GreekNumeral::try_from(u16::MAX).is_err(),
+            u32::from(u16::MAX) > MAX
+        );
+    }
+
     #[test]
     fn test_greek_numeral_to_string() {
         assert_eq!(GreekNumeral::new(0).unwrap().to_string(), "𐆊'");
         assert_eq!(GreekNumeral::new(1).unwrap().to_string(), "Α'");
         assert_eq!(GreekNumeral::new(616).unwrap().to_string(), "ΧΙϜ'");
         assert_eq!(GreekNumeral::new(1984).unwrap().to_string(), "͵ΑϠΠΔ'");
+        assert_eq!(GreekNumeral::new(300).unwrap().to_string(), "Τ'");
+        assert_eq!(GreekNumeral::new(400).unwrap().to_string(), "Υ'");
+        assert_eq!(GreekNumeral::new(6000).unwrap().to_string(), "͵Ϝ'");
+    }
+
+    #[test]
+    #[cfg(feature = "large-range")]
+    fn test_greek_numeral_to_string_large_range() {
+        assert_eq!(GreekNumeral(999_999).to_string(), "͵Ϡ͵Ϙ͵ΘϠϘΘ'");
+    }
+
+    #[test]
+    fn test_greek_numeral_display_alternate() {
+        let num = GreekNumeral::new(42).unwrap();
+        assert_eq!(format!("{}", num), "ΜΒ'");
+        assert_eq!(format!("{:#}", num), "ΜΒ");
+        assert_eq!(format!("{:#}", GreekNumeral::new(0).unwrap()), "𐆊");
+    }
+
+    #[test]
+    fn test_greek_numeral_distinct_glyphs() {
+        let two = GreekNumeral::new(2).unwrap();
+        assert_eq!(two.distinct_glyphs(Case::Upper), BTreeSet::from(['Β']));
+        let repeated = GreekNumeral::new(2002).unwrap();
+        assert_eq!(repeated.glyphs(Case::Upper).len(), 4);
+        assert_eq!(
+            repeated.distinct_glyphs(Case::Upper),
+            BTreeSet::from(['͵', 'Β'])
+        );
+        assert_eq!(
+            repeated.distinct_glyphs(Case::Lower),
+            BTreeSet::from(['͵', 'β'])
+        );
+        assert_eq!(
+            GreekNumeral(0).distinct_glyphs(Case::Upper),
+            BTreeSet::from(['𐆊'])
+        );
+    }
+
+    #[test]
+    fn test_greek_numeral_shares_glyph_with() {
+        let answer = GreekNumeral::new(42).unwrap();
+        assert!(answer.shares_glyph_with(GreekNumeral(40), Case::Upper));
+        assert!(answer.shares_glyph_with(GreekNumeral(40), Case::Lower));
+        assert!(!answer.shares_glyph_with(GreekNumeral(3), Case::Upper));
+        assert!(answer.shares_glyph_with(GreekNumeral(2002), Case::Upper));
+        assert!(GreekNumeral(1).shares_glyph_with(GreekNumeral(1000), Case::Upper));
+        assert!(!GreekNumeral(1000).shares_glyph_with(GreekNumeral(2000), Case::Upper));
+        assert!(!GreekNumeral(0).shares_glyph_with(answer, Case::Upper));
+        assert!(GreekNumeral(0).shares_glyph_with(GreekNumeral(0), Case::Upper));
+    }
+
+    #[test]
+    fn test_greek_numeral_display_padding() {
+        let num = GreekNumeral::new(42).unwrap();
+        assert_eq!(format!("{num:>8}"), "     ΜΒ'");
+        assert_eq!(format!("{num:8}"), "ΜΒ'     ");
+        assert_eq!(format!("{num:^8}"), "  ΜΒ'   ");
+        assert_eq!(format!("{num:*>6}"), "***ΜΒ'");
+        assert_eq!(format!("{num:>#4}"), "  ΜΒ");
+        assert_eq!(format!("{num:>2}"), "ΜΒ'");
+        let num = GreekNumeral::new(1984).unwrap();
+        assert_eq!(format!("{num:>8}").chars().count(), 8);
+        assert_eq!(format!("{num:>8}"), "  ͵ΑϠΠΔ'");
+        assert_eq!(format!("{:>5}", LowerGreek(GreekNumeral(42))), "  μβ'");
+        let debit = SignedGreek::new(-42).unwrap();
+        assert_eq!(format!("{debit:>6}"), "  \u{2212}ΜΒ'");
+    }
+
+    #[test]
+    fn test_greek_numeral_debug() {
+        let num = GreekNumeral::new(42).unwrap();
+        assert_eq!(format!("{num:?}"), r#"GreekNumeral(42 = "ΜΒ'")"#);
+        assert_eq!(format!("{num:#?}"), r#"GreekNumeral(42 = "ΜΒ'")"#);
+        assert_eq!(
+            format!("{:?}", GreekNumeral::new(0).unwrap()),
+            r#"GreekNumeral(0 = "𐆊'")"#
+        );
+        assert_eq!(
+            format!("{:?}", LowerGreek(num)),
+            r#"LowerGreek(GreekNumeral(42 = "ΜΒ'"))"#
+        );
+    }
+
+    #[test]
+    fn test_lower_greek_display() {
+        let num = GreekNumeral::new(42).unwrap();
+        assert_eq!(format!("{}", LowerGreek(num)), "μβ'");
+        assert_eq!(format!("{:#}", LowerGreek(num)), "μβ");
+        assert_eq!(LowerGreek(num).to_string(), num.to_lowercase());
+    }
+
+    #[test]
+    fn test_signed_greek_display() {
+        let credit = SignedGreek::new(42).unwrap();
+        assert_eq!(credit.to_string(), "ΜΒ'");
+        let debit = SignedGreek::new(-42).unwrap();
+        assert_eq!(debit.numeral(), GreekNumeral(42));
+        assert!(debit.is_negative());
+        assert!(!credit.is_negative());
+        assert_eq!(debit.to_string(), "\u{2212}ΜΒ'");
+        assert_eq!(format!("{debit:#}"), "\u{2212}ΜΒ");
+        let zero = SignedGreek::new(0).unwrap();
+        assert_eq!(zero.to_string(), "𐆊'");
+        assert!(!zero.is_negative());
+        let max = i32::try_from(MAX).unwrap();
+        assert!(SignedGreek::new(-max).is_ok());
+        let err = SignedGreek::new(-max - 1).unwrap_err();
+        assert_eq!(err.value, i128::from(-max - 1));
+        assert!(SignedGreek::new(i32::MIN).is_err());
+    }
+
+    #[test]
+    fn test_greek_numeral_to_string_with_case() {
+        for i in 1..=10 {
+            let num = GreekNumeral::new(i).unwrap();
+            let upper = num.to_string_with_case(Case::Upper);
+            let lower = num.to_string_with_case(Case::Lower);
+            assert_eq!(upper, num.to_uppercase());
+            assert_eq!(lower, num.to_lowercase());
+            assert_eq!(lower, upper.to_lowercase());
+        }
+        let num = GreekNumeral::new(10).unwrap();
+        assert_eq!(num.to_string_with_case(Case::Upper), "Ι'");
+        assert_eq!(num.to_string_with_case(Case::Lower), "ι'");
+    }
+
+    #[test]
+    fn test_greek_numeral_to_both_cases() {
+        for i in [0, 1, 42, 200, 616, 1984, 9999, MAX] {
+            let num = GreekNumeral::new(i).unwrap();
+            assert_eq!(
+                num.to_both_cases(),
+                (num.to_uppercase(), num.to_lowercase())
+            );
+        }
+    }
+
+    #[test]
+    fn test_greek_numeral_to_cow() {
+        let one = GreekNumeral::new(1).unwrap();
+        assert!(matches!(one.to_cow(Case::Upper), Cow::Borrowed("Α'")));
+        assert!(matches!(one.to_cow(Case::Lower), Cow::Borrowed("α'")));
+        let answer = GreekNumeral::new(42).unwrap();
+        assert!(matches!(answer.to_cow(Case::Upper), Cow::Owned(_)));
+        assert_eq!(answer.to_cow(Case::Upper), "ΜΒ'");
+        assert!(matches!(
+            GreekNumeral::new(0).unwrap().to_cow(Case::Upper),
+            Cow::Borrowed("𐆊'")
+        ));
+        assert!(matches!(
+            GreekNumeral::new(4000).unwrap().to_cow(Case::Lower),
+            Cow::Borrowed("͵δ'")
+        ));
+        for i in MIN..=MAX {
+            let num = GreekNumeral(i);
+            assert_eq!(num.to_cow(Case::Upper), num.to_uppercase());
+            assert_eq!(num.to_cow(Case::Lower), num.to_lowercase());
+        }
+    }
+
+    #[test]
+    fn test_greek_numeral_glyphs() {
+        let num = GreekNumeral::new(1234).unwrap();
+        let upper = num.glyphs(Case::Upper);
+        assert_eq!(upper, vec!['͵', 'Α', 'Σ', 'Λ', 'Δ', '\'']);
+        assert_eq!(upper.len(), num.to_uppercase().chars().count());
+        assert_eq!(upper.iter().collect::<String>(), num.to_uppercase());
+        let lower = num.glyphs(Case::Lower);
+        assert_eq!(lower.iter().collect::<String>(), num.to_lowercase());
+        assert_eq!(lower.last(), Some(&'\''));
+        assert_eq!(
+            GreekNumeral::new(0).unwrap().glyphs(Case::Lower),
+            vec!['𐆊', '\'']
+        );
+    }
+
+    #[test]
+    fn test_greek_numeral_to_utf16() {
+        assert_eq!(GreekNumeral(42).to_utf16(Case::Upper), [0x39c, 0x392, 0x27]);
+        let zero = GreekNumeral(0).to_utf16(Case::Upper);
+        assert_eq!(zero, [0xd800, 0xdd8a, 0x27]);
+        assert_eq!(String::from_utf16(&zero).unwrap(), "𐆊'");
+        for i in [1, 42, 616, 1984, MAX] {
+            let num = GreekNumeral::new(i).unwrap();
+            for case in [Case::Upper, Case::Lower] {
+                let units = num.to_utf16(case);
+                assert_eq!(
+                    String::from_utf16(&units).unwrap(),
+                    num.to_string_with_case(case)
+                );
+                assert_eq!(units.len(), num.glyph_count() + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_greek_numeral_as_greek_bytes() {
+        let num = GreekNumeral::new(1984).unwrap();
+        let upper = num.as_greek_bytes(Case::Upper);
+        assert_eq!(String::from_utf8(upper).unwrap(), "͵ΑϠΠΔ'");
+        let lower = num.as_greek_bytes(Case::Lower);
+        assert_eq!(core::str::from_utf8(&lower), Ok("͵αϡπδ'"));
+        assert_eq!(
+            GreekNumeral::new(0).unwrap().as_greek_bytes(Case::Upper),
+            [0xf0, 0x90, 0x86, 0x8a, b'\'']
+        );
+    }
+
+    #[test]
+    fn test_greek_numeral_write_to() {
+        let mut buf = String::new();
+        for i in [1, 42, 0, 616] {
+            GreekNumeral::new(i)
+                .unwrap()
+                .write_to(&mut buf, Case::Upper);
+        }
+        assert_eq!(buf, "Α'ΜΒ'𐆊'ΧΙϜ'");
+        buf.clear();
+        GreekNumeral::new(42)
+            .unwrap()
+            .write_to(&mut buf, Case::Lower);
+        buf.push(' ');
+        GreekNumeral::new(1984)
+            .unwrap()
+            .write_to(&mut buf, Case::Lower);
+        assert_eq!(buf, "μβ' ͵αϡπδ'");
+    }
+
+    #[test]
+    fn test_greek_numeral_write_io() {
+        for i in [0, 1, 42, 616, 1984, MAX] {
+            let num = GreekNumeral::new(i).unwrap();
+            let mut upper: Vec<u8> = Vec::new();
+            num.write_io(&mut upper, Case::Upper).unwrap();
+            assert_eq!(upper, num.to_uppercase().into_bytes());
+            let mut lower: Vec<u8> = Vec::new();
+            num.write_io(&mut lower, Case::Lower).unwrap();
+            assert_eq!(lower, num.to_lowercase().into_bytes());
+        }
+    }
+
+    #[test]
+    fn test_greek_numeral_format_with() {
+        let num = GreekNumeral::new(42).unwrap();
+        assert_eq!(num.format_with(Format::default()), "ΜΒ'");
+        let opts = Format {
+            with_keraia: false,
+            ..Format::default()
+        };
+        assert_eq!(num.format_with(opts), "ΜΒ");
+        let opts = Format {
+            case: Case::Lower,
+            with_keraia: false,
+            ..Format::default()
+        };
+        assert_eq!(num.format_with(opts), "μβ");
+        assert_eq!(num.to_uppercase(), "ΜΒ'");
+        assert_eq!(num.to_lowercase(), "μβ'");
+    }
+
+    #[test]
+    fn test_greek_numeral_format_zero() {
+        let zero = GreekNumeral::new(0).unwrap();
+        assert_eq!(zero.format_with(Format::default()), "𐆊'");
+        let opts = Format {
+            zero: "ο",
+            ..Format::default()
+        };
+        assert_eq!(zero.format_with(opts), "ο'");
+        let opts = Format {
+            with_keraia: false,
+            zero: "○",
+            ..Format::default()
+        };
+        assert_eq!(zero.format_with(opts), "○");
+        assert_eq!(GreekNumeral::new(42).unwrap().format_with(opts), "ΜΒ");
+    }
+
+    #[test]
+    fn test_greek_numeral_stem_and_keraia() {
+        for i in [0, 1, 42, 616, 1984, MAX] {
+            let num = GreekNumeral::new(i).unwrap();
+            let (stem, keraia) = num.stem_and_keraia(Case::Upper);
+            assert_eq!(stem + keraia, num.to_uppercase());
+            let (stem, keraia) = num.stem_and_keraia(Case::Lower);
+            assert_eq!(stem + keraia, num.to_lowercase());
+        }
+        assert_eq!(
+            GreekNumeral(1984).stem_and_keraia(Case::Lower),
+            (String::from("͵αϡπδ"), "'")
+        );
+    }
+
+    #[test]
+    fn test_greek_numeral_to_overlined() {
+        let num = GreekNumeral::new(1234).unwrap();
+        assert_eq!(num.to_overlined(), "͵Α\u{305}Σ\u{305}Λ\u{305}Δ\u{305}");
+        let letters = num.to_overlined().chars().filter(|c| *c != '͵').count();
+        let overlines = num.to_overlined().matches('\u{305}').count();
+        assert_eq!(letters, 2 * overlines);
+        assert_eq!(GreekNumeral::new(0).unwrap().to_overlined(), "𐆊\u{305}");
+    }
+
+    #[test]
+    #[cfg(feature = "html")]
+    fn test_greek_numeral_to_html() {
+        let num = GreekNumeral::new(42).unwrap();
+        assert_eq!(
+            num.to_html(Case::Upper),
+            concat!(
+                r#"<span class="greek-place" data-value="40">Μ</span>"#,
+                r#"<span class="greek-place" data-value="2">Β</span>"#,
+                r#"<span class="greek-keraia">'</span>"#,
+            )
+        );
+        assert!(
+            num.to_html(Case::Lower)
+                .contains(r#"data-value="40">μ</span>"#)
+        );
+        assert_eq!(
+            GreekNumeral::new(0).unwrap().to_html(Case::Upper),
+            r#"<span class="greek-place" data-value="0">𐆊</span><span class="greek-keraia">'</span>"#
+        );
+        let html = GreekNumeral::new(1984).unwrap().to_html(Case::Upper);
+        assert!(html.starts_with(r#"<span class="greek-place" data-value="1000">͵Α</span>"#));
+        assert_eq!(html.matches("greek-place").count(), 4);
+    }
+
+    #[test]
+    #[cfg(feature = "tex")]
+    fn test_greek_numeral_to_tex() {
+        let num = GreekNumeral::new(42).unwrap();
+        assert_eq!(num.to_tex(Case::Upper), r"\textgreek{ΜΒ\textdexiakeraia}");
+        assert_eq!(num.to_tex(Case::Lower), r"\textgreek{μβ\textdexiakeraia}");
+        assert_eq!(
+            GreekNumeral::new(1984).unwrap().to_tex(Case::Lower),
+            r"\textgreek{͵αϡπδ\textdexiakeraia}"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "roman")]
+    fn test_greek_numeral_to_roman() {
+        assert_eq!(GreekNumeral(42).to_roman().as_deref(), Some("XLII"));
+        assert_eq!(GreekNumeral(1).to_roman().as_deref(), Some("I"));
+        assert_eq!(GreekNumeral(1984).to_roman().as_deref(), Some("MCMLXXXIV"));
+        assert_eq!(GreekNumeral(3999).to_roman().as_deref(), Some("MMMCMXCIX"));
+        assert_eq!(GreekNumeral(0).to_roman(), None);
+        assert_eq!(GreekNumeral(4000).to_roman(), None);
+        assert_eq!(GreekNumeral(5000).to_roman(), None);
+    }
+
+    #[test]
+    fn test_greek_numeral_to_ascii_translit() {
+        let num = GreekNumeral::new(42).unwrap();
+        assert_eq!(num.to_ascii_translit(Case::Upper), "*M*B'");
+        assert_eq!(num.to_ascii_translit(Case::Lower), "MB'");
+        let num = GreekNumeral::new(1996).unwrap();
+        assert_eq!(num.to_ascii_translit(Case::Upper), "#22*A*#5*#3*V'");
+        assert_eq!(num.to_ascii_translit(Case::Lower), "#22A#5#3V'");
+        assert_eq!(
+            GreekNumeral::new(0).unwrap().to_ascii_translit(Case::Lower),
+            "0'"
+        );
+        for i in [1, 42, 616, 1984, MAX] {
+            assert!(
+                GreekNumeral::new(i)
+                    .unwrap()
+                    .to_ascii_translit(Case::Upper)
+                    .is_ascii()
+            );
+        }
+    }
+
+    #[test]
+    fn test_greek_numeral_to_ordinal() {
+        assert_eq!(GreekNumeral::new(1).unwrap().to_ordinal(), "Αος");
+        assert_eq!(GreekNumeral::new(2).unwrap().to_ordinal(), "Βος");
+        assert_eq!(GreekNumeral::new(10).unwrap().to_ordinal(), "Ιος");
+        assert_eq!(GreekNumeral::new(1984).unwrap().to_ordinal(), "͵ΑϠΠΔος");
+        let num = GreekNumeral::new(42).unwrap();
+        assert_ne!(num.to_ordinal(), num.to_uppercase());
+    }
+
+    #[test]
+    fn test_greek_numeral_format_sampi() {
+        let num = GreekNumeral::new(900).unwrap();
+        assert_eq!(num.format_with(Format::default()), "\u{3e0}'");
+        let archaic = Format {
+            sampi: SampiForm::Archaic,
+            ..Format::default()
+        };
+        assert_eq!(num.format_with(archaic), "\u{372}'");
+        let lower = Format {
+            case: Case::Lower,
+            ..archaic
+        };
+        assert_eq!(num.format_with(lower), "\u{373}'");
+    }
+
+    #[test]
+    #[cfg(feature = "large-range")]
+    fn test_greek_numeral_format_sampi_myriads() {
+        let archaic = Format {
+            sampi: SampiForm::Archaic,
+            ..Format::default()
+        };
+        let num = GreekNumeral::new(900_900).unwrap();
+        assert_eq!(num.format_with(Format::default()), "͵\u{3e0}\u{3e0}'");
+        assert_eq!(num.format_with(archaic), "͵\u{372}\u{372}'");
+    }
+
+    #[test]
+    fn test_greek_numeral_format_six() {
+        let six = GreekNumeral::new(6).unwrap();
+        let six_thousand = GreekNumeral::new(6000).unwrap();
+        let cases = [
+            (SixForm::Stigma, "Ϛ'", "͵Ϛ'", "ϛ'"),
+            (SixForm::Digamma, "Ϝ'", "͵Ϝ'", "ϝ'"),
+            (SixForm::SigmaTau, "ΣΤ'", "͵Σ͵Τ'", "στ'"),
+        ];
+        for (form, upper, thousands, lower) in cases {
+            let opts = Format {
+                six: form,
+                ..Format::default()
+            };
+            assert_eq!(six.format_with(opts), upper);
+            assert_eq!(six_thousand.format_with(opts), thousands);
+            let opts = Format {
+                case: Case::Lower,
+                ..opts
+            };
+            assert_eq!(six.format_with(opts), lower);
+        }
+        assert_eq!(six.format_with(Format::default()), "Ϝ'");
+    }
+
+    #[test]
+    fn test_greek_numeral_format_koppa() {
+        let ninety = GreekNumeral::new(90).unwrap();
+        let lower = Format {
+            case: Case::Lower,
+            ..Format::default()
+        };
+        assert_eq!(ninety.format_with(Format::default()), "\u{3d8}'");
+        assert_eq!(ninety.format_with(lower), "\u{3d9}'");
+        let numeral = Format {
+            koppa: KoppaForm::Numeral,
+            ..Format::default()
+        };
+        assert_eq!(ninety.format_with(numeral), "\u{3de}'");
+        assert_eq!(
+            ninety.format_with(Format {
+                koppa: KoppaForm::Numeral,
+                ..lower
+            }),
+            "\u{3df}'"
+        );
     }
 
-    //    #[test]
-    //    #[cfg(feature = "std")]
-    //    fn test_roman_numeral_round_trip() {
-    //        for i in 1..=49_999 {
-    //            let r = GreekNumeral::new(i).unwrap().to_string();
-    //            let parsed: GreekNumeral = r.parse().unwrap();
-    //            let val = parsed.0;
-    //            assert_eq!(val, i);
-    //        }
-    //    }
+    #[test]
+    #[cfg(feature = "large-range")]
+    fn test_greek_numeral_format_koppa_myriads() {
+        let numeral = Format {
+            koppa: KoppaForm::Numeral,
+            ..Format::default()
+        };
+        let num = GreekNumeral::new(90_090).unwrap();
+        assert_eq!(num.format_with(numeral), "͵\u{3de}\u{3de}'");
+    }
+
+    #[test]
+    fn test_greek_numeral_format_per_group_keraia() {
+        let num = GreekNumeral::new(1234).unwrap();
+        assert_eq!(num.format_with(Format::default()), "͵ΑΣΛΔ'");
+        let grouped = Format {
+            per_group_keraia: true,
+            ..Format::default()
+        };
+        assert_eq!(num.format_with(grouped), "͵Α'ΣΛΔ'");
+        assert_eq!(
+            num.format_with(Format {
+                with_keraia: false,
+                ..grouped
+            }),
+            "͵Α'ΣΛΔ"
+        );
+        assert_eq!(GreekNumeral::new(2000).unwrap().format_with(grouped), "͵Β'");
+        assert_eq!(
+            GreekNumeral::new(2000).unwrap().format_with(Format {
+                with_keraia: false,
+                ..grouped
+            }),
+            "͵Β'"
+        );
+        assert_eq!(GreekNumeral::new(42).unwrap().format_with(grouped), "ΜΒ'");
+        assert_eq!(
+            GreekNumeral::new(6006).unwrap().format_with(Format {
+                six: SixForm::SigmaTau,
+                ..grouped
+            }),
+            "͵ΣΤ'ΣΤ'"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "large-range")]
+    fn test_greek_numeral_format_per_group_keraia_myriads() {
+        let grouped = Format {
+            per_group_keraia: true,
+            case: Case::Lower,
+            ..Format::default()
+        };
+        let num = GreekNumeral::new(12_234).unwrap();
+        assert_eq!(num.format_with(grouped), "͵ιβ'σλδ'");
+    }
+
+    #[test]
+    fn test_greek_numeral_format_final_sigma() {
+        let lower = Format {
+            case: Case::Lower,
+            ..Format::default()
+        };
+        let final_sigma = Format {
+            final_sigma: true,
+            ..lower
+        };
+        let two_hundred = GreekNumeral::new(200).unwrap();
+        assert_eq!(two_hundred.format_with(lower), "σ'");
+        assert_eq!(two_hundred.format_with(final_sigma), "ς'");
+        let num = GreekNumeral::new(230).unwrap();
+        assert_eq!(num.format_with(lower), "σλ'");
+        assert_eq!(num.format_with(final_sigma), "σλ'");
+        let num = GreekNumeral::new(1200).unwrap();
+        assert_eq!(num.format_with(final_sigma), "͵ας'");
+        assert_eq!(
+            two_hundred.format_with(Format {
+                final_sigma: true,
+                ..Format::default()
+            }),
+            "Σ'"
+        );
+        assert_eq!(
+            "ς'".parse::<GreekNumeral>(),
+            Ok(two_hundred),
+            "final sigma must still parse"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "large-range")]
+    fn test_greek_numeral_format_final_sigma_myriads() {
+        let final_sigma = Format {
+            case: Case::Lower,
+            final_sigma: true,
+            ..Format::default()
+        };
+        let num = GreekNumeral::new(200_000).unwrap();
+        assert_eq!(num.format_with(final_sigma), "͵ς'");
+        let num = GreekNumeral::new(200_200).unwrap();
+        assert_eq!(num.format_with(final_sigma), "͵σς'");
+    }
+
+    #[test]
+    fn test_greek_numeral_format_combining_keraia() {
+        use unicode_normalization::UnicodeNormalization;
+
+        let num = GreekNumeral::new(42).unwrap();
+        let spacing: Vec<char> = num.format_with(Format::default()).chars().collect();
+        assert_eq!(spacing, ['\u{39c}', '\u{392}', '\'']);
+        let combining = Format {
+            combining_keraia: true,
+            ..Format::default()
+        };
+        let chars: Vec<char> = num.format_with(combining).chars().collect();
+        assert_eq!(chars, ['\u{39c}', '\u{392}', '\u{34f}', '\u{301}']);
+        for i in [1, 5, 10, 42, 800, 1001, MAX] {
+            let num = GreekNumeral::new(i).unwrap();
+            let rendered = num.format_with(combining);
+            let nfc: String = rendered.nfc().collect();
+            assert_eq!(nfc, rendered, "{i} must not compose under NFC");
+            assert!(!nfc.contains(['Ά', 'Έ', 'Ί', 'Ώ']));
+            assert_eq!(rendered.parse(), Ok(num));
+            let lower = num.format_with(Format {
+                case: Case::Lower,
+                ..combining
+            });
+            assert_eq!(lower.parse(), Ok(num));
+            assert_eq!(
+                strip_keraia(&rendered),
+                num.format_with(Format {
+                    with_keraia: false,
+                    ..combining
+                })
+            );
+        }
+        assert_eq!(
+            num.format_with(Format {
+                with_keraia: false,
+                ..combining
+            }),
+            "ΜΒ"
+        );
+    }
+
+    #[test]
+    fn test_greek_numeral_format_empty_zero() {
+        let zero = GreekNumeral::new(0).unwrap();
+        assert_eq!(zero.format_with(Format::default()), "𐆊'");
+        let empty = Format {
+            empty_zero: true,
+            ..Format::default()
+        };
+        assert_eq!(zero.format_with(empty), "");
+        assert_eq!(
+            zero.format_with(Format {
+                zero: "ο", ..empty
+            }),
+            ""
+        );
+        assert_eq!(GreekNumeral::new(42).unwrap().format_with(empty), "ΜΒ'");
+    }
+
+    #[test]
+    fn test_greek_numeral_format_separator() {
+        let spaced = Format {
+            separator: Some(" "),
+            ..Format::default()
+        };
+        assert_eq!(GreekNumeral(42).format_with(spaced), "Μ Β");
+        assert_eq!(GreekNumeral(2456).format_with(spaced), "͵Β Υ Ν Ϝ");
+        assert_eq!(GreekNumeral(7).format_with(spaced), "Ζ");
+        assert_eq!(GreekNumeral(0).format_with(spaced), "𐆊");
+        assert_eq!(
+            GreekNumeral(2456).format_with(Format {
+                case: Case::Lower,
+                separator: Some(" + "),
+                ..spaced
+            }),
+            "͵β + υ + ν + ϝ"
+        );
+        assert_eq!(
+            GreekNumeral(1234).format_with(Format {
+                per_group_keraia: true,
+                ..spaced
+            }),
+            "͵Α' Σ Λ Δ"
+        );
+        assert_eq!(GreekNumeral(42).format_with(Format::default()), "ΜΒ'");
+    }
+
+    #[test]
+    fn test_greek_numeral_place_breakdown() {
+        assert_eq!(GreekNumeral(42).place_breakdown(), vec![(40, 1), (2, 1)]);
+        assert_eq!(
+            GreekNumeral(9909).place_breakdown(),
+            vec![(9000, 1), (900, 1), (9, 1)]
+        );
+        assert_eq!(GreekNumeral(0).place_breakdown(), vec![]);
+        let total: u32 = GreekNumeral(1984)
+            .place_breakdown()
+            .into_iter()
+            .map(|(value, count)| value * u32::from(count))
+            .sum();
+        assert_eq!(total, 1984);
+    }
+
+    #[test]
+    fn test_greek_numeral_components() {
+        let num = GreekNumeral::new(42).unwrap();
+        let upper: Vec<_> = num.components().collect();
+        assert_eq!(upper, vec![(40, "Μ"), (2, "Β")]);
+        let lower: Vec<_> = num.lowercase_components().collect();
+        assert_eq!(lower, vec![(40, "μ"), (2, "β")]);
+        assert_eq!(GreekNumeral::new(0).unwrap().components().count(), 0);
+        let glyphs: String = GreekNumeral::new(1984)
+            .unwrap()
+            .components()
+            .map(|(_, glyph)| glyph)
+            .collect();
+        assert_eq!(glyphs, "͵ΑϠΠΔ");
+    }
+
+    #[test]
+    fn test_greek_numeral_glyph_count() {
+        for i in [0, 1, 42, 616, 1984, 9999, MAX] {
+            let num = GreekNumeral::new(i).unwrap();
+            assert_eq!(num.glyph_count(), num.to_uppercase().chars().count() - 1);
+            assert_eq!(num.glyph_count(), num.to_lowercase().chars().count() - 1);
+        }
+        assert_eq!(GreekNumeral::new(0).unwrap().glyph_count(), 1);
+        assert_eq!(GreekNumeral::new(9999).unwrap().glyph_count(), 5);
+        #[cfg(feature = "large-range")]
+        assert_eq!(GreekNumeral::new(999_999).unwrap().glyph_count(), 9);
+    }
+
+    #[test]
+    fn test_greek_numerals_iter_ext() {
+        let numerals: Result<Vec<GreekNumeral>> = (1..=3).greek_numerals().collect();
+        assert_eq!(
+            numerals,
+            Ok(vec![GreekNumeral(1), GreekNumeral(2), GreekNumeral(3)])
+        );
+        let results: Vec<_> = [MAX - 1, MAX, MAX + 1, u32::MAX]
+            .into_iter()
+            .greek_numerals()
+            .collect();
+        assert_eq!(results[0], Ok(GreekNumeral(MAX - 1)));
+        assert_eq!(results[1], Ok(GreekNumeral::MAX));
+        assert_eq!(results[2].unwrap_err().value, i128::from(MAX + 1));
+        assert_eq!(results[3].unwrap_err().value, i128::from(u32::MAX));
+        let numerals: Result<Vec<GreekNumeral>> = (MAX..).greek_numerals().collect();
+        assert!(numerals.is_err());
+    }
+
+    #[test]
+    fn test_greek_numeral_byte_len() {
+        assert_eq!(GreekNumeral::new(0).unwrap().byte_len(Case::Upper), 5);
+        assert_eq!(GreekNumeral::new(1).unwrap().byte_len(Case::Lower), 3);
+        for i in (MIN..=MAX).step_by(7).chain([9999, MAX]) {
+            let num = GreekNumeral::new(i).unwrap();
+            assert_eq!(num.byte_len(Case::Upper), num.to_uppercase().len());
+            assert_eq!(num.byte_len(Case::Lower), num.to_lowercase().len());
+        }
+    }
+
+    #[test]
+    fn test_greek_numeral_to_acrophonic() {
+        assert_eq!(GreekNumeral::new(1).unwrap().to_acrophonic(), "Ι");
+        assert_eq!(GreekNumeral::new(5).unwrap().to_acrophonic(), "\u{10143}");
+        assert_eq!(GreekNumeral::new(49).unwrap().to_acrophonic(), "ΔΔΔΔ𐅃ΙΙΙΙ");
+        assert_eq!(GreekNumeral::new(500).unwrap().to_acrophonic(), "\u{10145}");
+    }
+
+    /// The original linear-scan conversion, kept to check the place lookup.
+    fn linear_scan(value: u32, case: Case) -> String {
+        let mut out = String::new();
+        let mut n = value;
+        if n == 0 {
+            out.push('𐆊');
+        } else {
+            for arithmos in ARITHMOI.iter() {
+                while n >= arithmos.arabic {
+                    n -= arithmos.arabic;
+                    out.push_str(arithmos.attic(case));
+                }
+            }
+        }
+        out.push('\'');
+        out
+    }
+
+    #[test]
+    fn test_greek_numeral_matches_linear_scan() {
+        for i in MIN..=MAX {
+            let num = GreekNumeral::new(i).unwrap();
+            assert_eq!(num.to_uppercase(), linear_scan(i, Case::Upper));
+            assert_eq!(num.to_lowercase(), linear_scan(i, Case::Lower));
+        }
+    }
+
+    #[test]
+    fn test_greek_numeral_round_trip() {
+        for i in 1..=MAX.min(49_999) {
+            let r = GreekNumeral::new(i).unwrap().to_string();
+            let parsed: GreekNumeral = r.parse().unwrap();
+            let val = parsed.0;
+            assert_eq!(val, i);
+        }
+    }
 }
@@ -0,0 +1,867 @@
+//! Parsing Greek numeral strings back into ``GreekNumeral`` values
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::str::FromStr;
+
+use unicode_normalization::UnicodeNormalization;
+
+use crate::table::{ARITHMOI, COMBINING_KERAIA, OVERLINE, THOUSANDS, ZERO};
+use crate::{Case, GreekNumeral, Result};
+
+/// The marks accepted as a trailing keraia: the ASCII apostrophe emitted by
+/// the string conversions, GREEK NUMERAL SIGN (U+0374) and its canonical
+/// equivalent MODIFIER LETTER PRIME (U+02B9).
+const KERAIAI: [char; 3] = ['\'', '\u{374}', '\u{2b9}'];
+
+/// Alternate single-character glyphs and the table glyph each stands for:
+/// stigma for digamma (6), archaic sampi for sampi (900) and numeral koppa for
+/// archaic koppa (90).
+const LEGACY: [(char, char); 6] = [
+    ('Ϛ', 'Ϝ'),
+    ('ϛ', 'ϝ'),
+    ('Ͳ', 'Ϡ'),
+    ('ͳ', 'ϡ'),
+    ('Ϟ', 'Ϙ'),
+    ('ϟ', 'ϙ'),
+];
+
+/// Returned as an error if a string is not a valid Greek numeral
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The string has no glyphs
+    Empty,
+    /// The string contains a character that is not a Greek numeral glyph
+    UnknownGlyph(char),
+    /// A glyph follows one of an equal or lower place value
+    OutOfOrder,
+    /// The value is greater than ``MAX``
+    OutOfRange,
+    /// The glyphs mix uppercase and lowercase
+    MixedCase,
+    /// The glyphs are not followed by a keraia
+    MissingKeraia,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Empty Greek numeral."),
+            Self::UnknownGlyph(c) => write!(f, "Unknown glyph {c:?} in Greek numeral."),
+            Self::OutOfOrder => write!(f, "Greek numeral glyphs are out of order."),
+            Self::OutOfRange => write!(f, "Greek numeral out of range."),
+            Self::MixedCase => write!(f, "Greek numeral mixes uppercase and lowercase."),
+            Self::MissingKeraia => write!(f, "Greek numeral is missing its keraia."),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+impl FromStr for GreekNumeral {
+    type Err = ParseError;
+
+    /// Parses a ``GreekNumeral`` from a string.
+    ///
+    /// The string must consist of glyphs in descending place order, all in
+    /// the same case, followed by a keraia. Stigma, sigma-tau (``ΣΤ``), the
+    /// archaic sampi and numeral koppa are read as their standard
+    /// equivalents, so every ``SixForm`` parses back. COMBINING
+    /// OVERLINE (U+0305) marks are ignored, and an overlined numeral needs no
+    /// keraia, so the output of ``to_overlined`` parses back.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = "ΜΒ'".parse()?;
+    ///    assert_eq!(answer.as_u32(), 42);
+    ///
+    /// Grammar
+    /// -------
+    ///
+    /// These examples are run as doc-tests, covering multiple places and the
+    /// ``͵`` thousands marker:
+    ///
+    /// ```
+    /// use arithmos::{GreekNumeral, ParseError};
+    ///
+    /// let answer: GreekNumeral = "ΜΒʹ".parse().unwrap();
+    /// assert_eq!(answer.as_u32(), 42);
+    ///
+    /// let year: GreekNumeral = "͵ΒΣΜΕʹ".parse().unwrap();
+    /// assert_eq!(year.as_u32(), 2245);
+    ///
+    /// assert_eq!("ΜΒ".parse::<GreekNumeral>(), Err(ParseError::MissingKeraia));
+    /// assert_eq!("ΒΜʹ".parse::<GreekNumeral>(), Err(ParseError::OutOfOrder));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_with_case(s).map(|(numeral, _)| numeral)
+    }
+}
+
+/// Parses a ``GreekNumeral`` as ``FromStr`` does, also returning the
+/// ``Case`` its glyphs were written in.
+///
+/// Mixed case is rejected as by ``FromStr``. 0 (ZERO) has no case and is
+/// reported as ``Case::Upper``.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    let (answer, case) = parse_with_case("μβ'")?;
+///    assert_eq!((answer.as_u32(), case), (42, Case::Lower));
+///
+pub fn parse_with_case(s: &str) -> Result<(GreekNumeral, Case), ParseError> {
+    let (numeral, case, rest) = parse_glyphs(normalize(s), false)?;
+    let mut after = rest.clone();
+    match after.next() {
+        Some(keraia) if KERAIAI.contains(&keraia) => match after.next() {
+            Some(c) => Err(ParseError::UnknownGlyph(c)),
+            None => Ok((numeral, case)),
+        },
+        Some(_) => Err(trailing_error(&rest)),
+        None if s.contains(OVERLINE) => Ok((numeral, case)),
+        None => Err(ParseError::MissingKeraia),
+    }
+}
+
+/// Returns whether ``s`` is a well-formed Greek numeral.
+///
+/// The glyphs must be in descending place order and all in the same case. The
+/// trailing keraia is optional.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    assert!(is_valid_greek_numeral("ΜΒ'"));
+///    assert!(is_valid_greek_numeral("μβ"));
+///    assert!(!is_valid_greek_numeral("ΒΜ'"));
+///
+#[must_use]
+pub fn is_valid_greek_numeral(s: &str) -> bool {
+    parse_glyphs(normalize(s), false).is_ok_and(|(_, _, mut rest)| match rest.next() {
+        Some(keraia) => KERAIAI.contains(&keraia) && rest.next().is_none(),
+        None => true,
+    })
+}
+
+/// Parses a ``GreekNumeral`` from untidy input, such as OCR output.
+///
+/// Unlike ``FromStr``, uppercase and lowercase glyphs may be mixed, the
+/// keraia is optional and surrounding whitespace is ignored. The glyphs must
+/// still be in descending place order.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    assert_eq!(parse_lenient(" Μβ ")?.as_u32(), 42);
+///
+pub fn parse_lenient(s: &str) -> Result<GreekNumeral, ParseError> {
+    let (numeral, _, rest) = parse_glyphs(normalize(strip_keraia(s.trim())), true)?;
+    match rest.clone().next() {
+        Some(_) => Err(trailing_error(&rest)),
+        None => Ok(numeral),
+    }
+}
+
+/// Parses the Greek numeral at the start of ``s``, returning its value and
+/// the number of bytes it took up, keraia included.
+///
+/// The rest of ``s`` is left for the caller, so a tokenizer can walk running
+/// text. The longest run of glyphs in descending place order is consumed; as
+/// with ``FromStr`` they must share a case, but the keraia is optional.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    let text = "ΜΒ' ἔτη";
+///    assert_eq!(parse_prefix(text)?, (GreekNumeral::new(42)?, 5));
+///
+pub fn parse_prefix(s: &str) -> Result<(GreekNumeral, usize), ParseError> {
+    let (numeral, case, mut rest) = parse_glyphs(normalize(s), false)?;
+    let mut consumed: usize = match numeral.0 {
+        0 => ZERO.chars().count(),
+        _ => numeral
+            .arithmoi()
+            .map(|arithmos| arithmos.attic(case).chars().count())
+            .sum(),
+    };
+    if rest.next().is_some_and(|c| KERAIAI.contains(&c)) {
+        consumed += 1;
+    }
+    // Normalization can change byte lengths and drops overlines, so find the
+    // longest prefix of ``s`` that normalizes to no more chars than were
+    // consumed.
+    let len = s
+        .char_indices()
+        .map(|(i, c)| i + c.len_utf8())
+        .take_while(|&end| normalize(&s[..end]).count() <= consumed)
+        .last()
+        .unwrap_or(0);
+    Ok((numeral, len))
+}
+
+/// Compares two Greek numeral strings by value rather than by code point.
+///
+/// Both strings are parsed as by ``FromStr``, so ``"Ωʹ"`` (800) sorts before
+/// ``"͵Αʹ"`` (1,000) even though ``͵`` precedes ``Ω`` in Unicode.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    assert_eq!(cmp_str("Ωʹ", "͵Αʹ")?, Ordering::Less);
+///
+pub fn cmp_str(a: &str, b: &str) -> Result<Ordering, ParseError> {
+    Ok(a.parse::<GreekNumeral>()?.cmp(&b.parse()?))
+}
+
+/// Removes one trailing keraia from ``s``, if present.
+///
+/// Any of the accepted keraia marks is removed, including the combining
+/// keraia; other input is returned unchanged.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    assert_eq!(strip_keraia("ΜΒ'"), "ΜΒ");
+///    assert_eq!(strip_keraia("ΜΒ\u{374}"), "ΜΒ");
+///    assert_eq!(strip_keraia("ΜΒ"), "ΜΒ");
+///
+#[must_use]
+pub fn strip_keraia(s: &str) -> &str {
+    s.strip_suffix(COMBINING_KERAIA)
+        .or_else(|| s.strip_suffix('\u{301}'))
+        .or_else(|| s.strip_suffix(KERAIAI))
+        .unwrap_or(s)
+}
+
+/// Re-renders a Greek numeral in the crate's canonical glyphs, keeping its
+/// case.
+///
+/// Alternate glyphs accepted by the parser, such as stigma (Ϛ) or sigma-tau
+/// (ΣΤ) for 6 and the archaic sampi (Ͳ) for 900, are replaced by the default ``Format`` glyphs,
+/// and any keraia becomes the ASCII apostrophe.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    assert_eq!(canonicalize("χιϛʹ")?, "χιϝ'");
+///
+#[cfg(feature = "std")]
+pub fn canonicalize(s: &str) -> Result<String, ParseError> {
+    let (numeral, case) = parse_with_case(s)?;
+    Ok(numeral.to_string_with_case(case))
+}
+
+/// Returns whether ``s`` is exactly what the crate renders for its value,
+/// in the case it is written in, and could not have been written for another
+/// value by older versions.
+///
+/// Strings that parse but use a form the renderer never produces, such as
+/// stigma (Ϛ) for 6 or GREEK NUMERAL SIGN (U+0374) as the keraia, return
+/// ``false``, as do strings that do not parse at all.
+///
+/// Versions that wrote rho for 300 and 400, and ``͵Ε`` for 6,000, left
+/// strings that read as valid numerals of another value. Any string with an
+/// unmarked rho (100) or ``͵Ε`` (5,000) is therefore ambiguous and also
+/// returns ``false``, so that stored data can be checked against its source.
+///
+/// Example
+/// -------
+///
+/// .. code-block:: rust
+///
+///    assert!(is_canonical("ΤΜΒ'"));
+///    assert!(!is_canonical("ΤΜΒʹ"));
+///    assert!(!is_canonical("ΡΜΒ'"));
+///
+#[must_use]
+#[cfg(feature = "std")]
+pub fn is_canonical(s: &str) -> bool {
+    canonicalize(s).is_ok_and(|canonical| canonical == s) && !is_ambiguous(s)
+}
+
+/// Returns whether ``s`` has a glyph that older versions also wrote for
+/// another value: rho for 300 and 400, or ``͵Ε`` for 6,000.
+#[cfg(feature = "std")]
+fn is_ambiguous(s: &str) -> bool {
+    let mut after_thousands = false;
+    s.chars().any(|c| {
+        let marked = core::mem::replace(&mut after_thousands, c == THOUSANDS);
+        match c {
+            'Ρ' | 'ρ' => !marked,
+            'Ε' | 'ε' => marked,
+            _ => false,
+        }
+    })
+}
+
+/// Normalizes ``s`` to NFC, drops overlines, reads the combining keraia as
+/// ``'``, folds final sigma (ς) into sigma (σ) and replaces ``LEGACY`` and
+/// ``SigmaTau`` glyphs, so that text copied from different sources matches
+/// the table glyphs.
+///
+/// The joiner of the combining keraia is dropped only after NFC, so that it
+/// still keeps the accent from composing with the glyph before it.
+fn normalize(s: &str) -> impl Iterator<Item = char> + Clone + '_ {
+    let joiner = COMBINING_KERAIA.chars().next();
+    let chars = s
+        .nfc()
+        .filter(move |&c| c != OVERLINE && Some(c) != joiner)
+        .map(|c| match c {
+            'ς' => 'σ',
+            '\u{301}' => '\'',
+            _ => LEGACY
+                .iter()
+                .find_map(|&(legacy, glyph)| (legacy == c).then_some(glyph))
+                .unwrap_or(c),
+        });
+    SigmaTau {
+        chars,
+        after_thousands: false,
+    }
+}
+
+/// Reads the ``SixForm::SigmaTau`` glyphs as digamma: ``ΣΤ`` for 6 and
+/// ``͵Σ͵Τ`` for 6,000.
+///
+/// Neither pair is otherwise valid, since sigma and tau share a place. A tau
+/// after a marked sigma without its own marker, as in ``͵ΣΤ`` for 200,300,
+/// is left alone.
+#[derive(Clone)]
+struct SigmaTau<I> {
+    chars: I,
+    after_thousands: bool,
+}
+
+impl<I: Iterator<Item = char> + Clone> Iterator for SigmaTau<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+        let after_thousands = core::mem::replace(&mut self.after_thousands, c == THOUSANDS);
+        let (tau, digamma) = match c {
+            'Σ' => ('Τ', 'Ϝ'),
+            'σ' => ('τ', 'ϝ'),
+            _ => return Some(c),
+        };
+        let mut ahead = self.chars.clone();
+        if after_thousands && ahead.next() != Some(THOUSANDS) {
+            return Some(c);
+        }
+        if ahead.next() != Some(tau) {
+            return Some(c);
+        }
+        self.chars = ahead;
+        Some(digamma)
+    }
+}
+
+/// Consumes ``glyph`` from the front of ``chars``, returning the remainder if
+/// it was present.
+fn strip_glyph<I: Iterator<Item = char> + Clone>(chars: &I, glyph: &str) -> Option<I> {
+    let mut rest = chars.clone();
+    glyph
+        .chars()
+        .all(|g| rest.next() == Some(g))
+        .then_some(rest)
+}
+
+/// Parses the glyphs at the front of ``chars``, returning the value, the case
+/// of the first glyph and the unconsumed remainder.
+///
+/// Glyphs of both cases are accepted together only if ``mixed_case`` is set.
+fn parse_glyphs<I: Iterator<Item = char> + Clone>(
+    chars: I,
+    mixed_case: bool,
+) -> Result<(GreekNumeral, Case, I), ParseError> {
+    if let Some(rest) = strip_glyph(&chars, ZERO) {
+        return Ok((GreekNumeral(0), Case::Upper, rest));
+    }
+
+    let mut rest = chars;
+    let mut value = 0;
+    let mut found: Option<Case> = None;
+    // Each decimal place contributes at most one glyph, so a place is left
+    // as soon as one of its nine entries matches.
+    for place in ARITHMOI.chunks(9) {
+        let matched = place.iter().find_map(|arithmos| {
+            [Case::Upper, Case::Lower].into_iter().find_map(|case| {
+                strip_glyph(&rest, arithmos.attic(case)).map(|tail| (arithmos, case, tail))
+            })
+        });
+        if let Some((arithmos, case, tail)) = matched {
+            if !mixed_case && found.is_some_and(|c| c != case) {
+                return Err(ParseError::MixedCase);
+            }
+            found = found.or(Some(case));
+            value += arithmos.arabic;
+            rest = tail;
+        }
+    }
+
+    match (found, rest.clone().next()) {
+        (Some(case), _) => Ok((GreekNumeral(value), case, rest)),
+        (None, Some(c)) if !KERAIAI.contains(&c) => Err(trailing_error(&rest)),
+        (None, _) => Err(ParseError::Empty),
+    }
+}
+
+/// Explains why parsing stopped at the front of ``rest``: either a glyph came
+/// too late to be consumed in place order, or it is not a glyph at all.
+fn trailing_error<I: Iterator<Item = char> + Clone>(rest: &I) -> ParseError {
+    let is_glyph = ARITHMOI.iter().any(|arithmos| {
+        [Case::Upper, Case::Lower]
+            .into_iter()
+            .any(|case| strip_glyph(rest, arithmos.attic(case)).is_some())
+    });
+    match rest.clone().next() {
+        Some(_) if is_glyph => ParseError::OutOfOrder,
+        Some(c) => ParseError::UnknownGlyph(c),
+        None => ParseError::Empty,
+    }
+}
+
+impl GreekNumeral {
+    /// Parses a ``GreekNumeral`` from a decimal string such as ``"9,999"``.
+    ///
+    /// Commas and whitespace are ignored anywhere in the string.
+    ///
+    /// Example
+    /// -------
+    ///
+    /// .. code-block:: rust
+    ///
+    ///    let answer: GreekNumeral = GreekNumeral::from_arabic_string(" 1,984 ")?;
+    ///    assert_eq!(answer.as_u32(), 1984);
+    ///
+    pub fn from_arabic_string(s: &str) -> Result<Self, ParseError> {
+        let mut digits = s.chars().filter(|&c| c != ',' && !c.is_whitespace());
+        let first = digits.next().ok_or(ParseError::Empty)?;
+        core::iter::once(first)
+            .chain(digits)
+            .try_fold(0_u32, |value, c| {
+                let digit = c.to_digit(10).ok_or(ParseError::UnknownGlyph(c))?;
+                value
+                    .checked_mul(10)
+                    .and_then(|value| value.checked_add(digit))
+                    .ok_or(ParseError::OutOfRange)
+            })
+            .and_then(|value| Self::new(value).map_err(|_| ParseError::OutOfRange))
+    }
+}
+
+impl TryFrom<&str> for GreekNumeral {
+    type Error = ParseError;
+
+    /// Creates a ``GreekNumeral`` from a ``&str``.
+    ///
+    /// Returns ``GreekNumeral`` or ``ParseError``.
+    fn try_from(value: &str) -> Result<Self, ParseError> {
+        value.parse()
+    }
+}
+
+impl TryFrom<char> for GreekNumeral {
+    type Error = ParseError;
+
+    /// Creates a ``GreekNumeral`` from a single glyph of either case, such as
+    /// ``'Μ'`` for 40.
+    ///
+    /// Returns ``ParseError::UnknownGlyph`` for any other ``char``, including
+    /// the keraia and the two-character thousands glyphs.
+    fn try_from(value: char) -> Result<Self, ParseError> {
+        let mut buf = [0; 4];
+        let glyph = normalize(value.encode_utf8(&mut buf));
+        if glyph.clone().eq(ZERO.chars()) {
+            return Ok(GreekNumeral(0));
+        }
+        ARITHMOI
+            .iter()
+            .find(|arithmos| {
+                [Case::Upper, Case::Lower]
+                    .into_iter()
+                    .any(|case| arithmos.attic(case).chars().eq(glyph.clone()))
+            })
+            .map(|arithmos| GreekNumeral(arithmos.arabic))
+            .ok_or(ParseError::UnknownGlyph(value))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("𐆊'".parse(), Ok(GreekNumeral(0)));
+        assert_eq!("Α'".parse(), Ok(GreekNumeral(1)));
+        assert_eq!("ΜΒ'".parse(), Ok(GreekNumeral(42)));
+        assert_eq!("ΧΙϜ'".parse(), Ok(GreekNumeral(616)));
+        assert_eq!("͵ΑϠΠΔ'".parse(), Ok(GreekNumeral(1984)));
+    }
+
+    #[test]
+    fn test_from_str_errors() {
+        let parse = |s: &str| s.parse::<GreekNumeral>();
+        assert_eq!(parse(""), Err(ParseError::Empty));
+        assert_eq!(parse("'"), Err(ParseError::Empty));
+        assert_eq!(parse("XLII'"), Err(ParseError::UnknownGlyph('X')));
+        assert_eq!(parse("ΜΒx'"), Err(ParseError::UnknownGlyph('x')));
+        assert_eq!(parse("ΜΒ''"), Err(ParseError::UnknownGlyph('\'')));
+        assert_eq!(parse("ΤΥ'"), Err(ParseError::OutOfOrder));
+        assert_eq!(parse("ΒΜ'"), Err(ParseError::OutOfOrder));
+        assert_eq!(parse("ΜΜ'"), Err(ParseError::OutOfOrder));
+        assert_eq!(parse("Μβ'"), Err(ParseError::MixedCase));
+        assert_eq!(parse("ΜΒ"), Err(ParseError::MissingKeraia));
+    }
+
+    #[test]
+    fn test_from_str_place_order() {
+        assert_eq!("͵ΑϠΠΔ'".parse(), Ok(GreekNumeral(1984)));
+        assert_eq!("ΚΑ'".parse(), Ok(GreekNumeral(21)));
+        let parse = |s: &str| s.parse::<GreekNumeral>();
+        assert_eq!(parse("ΑΚ'"), Err(ParseError::OutOfOrder));
+        assert_eq!(parse("ΙΚ'"), Err(ParseError::OutOfOrder));
+        assert_eq!(parse("ΚΙ'"), Err(ParseError::OutOfOrder));
+        assert_eq!(parse("ΡΡ'"), Err(ParseError::OutOfOrder));
+        assert_eq!(parse("͵Α͵Β'"), Err(ParseError::OutOfOrder));
+        assert_eq!(parse("ΑΑ'"), Err(ParseError::OutOfOrder));
+        assert_eq!(parse("δ͵α'"), Err(ParseError::OutOfOrder));
+        assert_eq!(parse_lenient("αΚ"), Err(ParseError::OutOfOrder));
+    }
+
+    #[test]
+    fn test_is_valid_greek_numeral() {
+        assert!(is_valid_greek_numeral("ΜΒ'"));
+        assert!(is_valid_greek_numeral("ΜΒ"));
+        assert!(is_valid_greek_numeral("μβʹ"));
+        assert!(is_valid_greek_numeral("͵ΑϠΠΔ'"));
+        assert!(is_valid_greek_numeral("𐆊"));
+        assert!(!is_valid_greek_numeral(""));
+        assert!(!is_valid_greek_numeral("'"));
+        assert!(!is_valid_greek_numeral("ʹ"));
+        assert!(!is_valid_greek_numeral("Μβ'"));
+        assert!(!is_valid_greek_numeral("ΒΜ'"));
+        assert!(!is_valid_greek_numeral("ΜΜ'"));
+        assert!(!is_valid_greek_numeral("ΚΙ'"));
+        assert!(!is_valid_greek_numeral("ΜΒ''"));
+        assert!(!is_valid_greek_numeral("42"));
+    }
+
+    #[test]
+    fn test_from_str_combining_keraia() {
+        assert_eq!("Α\u{34f}\u{301}".parse(), Ok(GreekNumeral(1)));
+        assert_eq!("ΜΒ\u{34f}\u{301}".parse(), Ok(GreekNumeral(42)));
+        assert_eq!("ΜΒ\u{301}".parse(), Ok(GreekNumeral(42)));
+        assert!(is_valid_greek_numeral("ω\u{34f}\u{301}"));
+        assert_eq!(parse_lenient(" Ι\u{34f}\u{301} "), Ok(GreekNumeral(10)));
+        assert_eq!(
+            parse_prefix("Ω\u{34f}\u{301} ἔτη"),
+            Ok((GreekNumeral(800), 6))
+        );
+    }
+
+    #[test]
+    fn test_from_str_sigma_tau() {
+        assert_eq!("ΣΤ'".parse(), Ok(GreekNumeral(6)));
+        assert_eq!("στ'".parse(), Ok(GreekNumeral(6)));
+        assert_eq!("ΧΙΣΤ'".parse(), Ok(GreekNumeral(616)));
+        assert_eq!("ΣΣΤ'".parse(), Ok(GreekNumeral(206)));
+        assert_eq!("͵Σ͵Τ'".parse(), Ok(GreekNumeral(6000)));
+        assert_eq!("͵σ͵τστ'".parse(), Ok(GreekNumeral(6006)));
+        assert_eq!(parse_with_case("στ'"), Ok((GreekNumeral(6), Case::Lower)));
+        assert_eq!("ΤΣ'".parse::<GreekNumeral>(), Err(ParseError::OutOfOrder));
+        assert_eq!("Στ'".parse::<GreekNumeral>(), Err(ParseError::OutOfOrder));
+        assert_eq!(parse_prefix("ΣΤ' ἔτη"), Ok((GreekNumeral(6), 5)));
+    }
+
+    #[test]
+    #[cfg(feature = "large-range")]
+    fn test_from_str_sigma_tau_myriads() {
+        assert_eq!("͵ΣΤ'".parse(), Ok(GreekNumeral(200_300)));
+        assert_eq!("͵ΣΣΤ'".parse(), Ok(GreekNumeral(200_006)));
+    }
+
+    #[test]
+    fn test_from_str_legacy_glyphs() {
+        assert_eq!("ΧΙϚ'".parse(), Ok(GreekNumeral(616)));
+        assert_eq!("ͳϟθ'".parse(), Ok(GreekNumeral(999)));
+        assert_eq!("͵Ϛ'".parse(), Ok(GreekNumeral(6000)));
+        assert!(is_valid_greek_numeral("Ͳ"));
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        assert_eq!(parse_lenient("Μβ'"), Ok(GreekNumeral(42)));
+        assert_eq!(parse_lenient("μΒ"), Ok(GreekNumeral(42)));
+        assert_eq!(parse_lenient("͵αϠπΔ"), Ok(GreekNumeral(1984)));
+        assert_eq!(parse_lenient("  χιϜ\u{374}\n"), Ok(GreekNumeral(616)));
+        assert_eq!(parse_lenient("ΜΒ"), Ok(GreekNumeral(42)));
+        assert_eq!(parse_lenient("ΜΒ'"), "ΜΒ'".parse());
+        assert_eq!(parse_lenient(""), Err(ParseError::Empty));
+        assert_eq!(parse_lenient(" ' "), Err(ParseError::Empty));
+        assert_eq!(parse_lenient("βΜ"), Err(ParseError::OutOfOrder));
+        assert_eq!(parse_lenient("Μ β"), Err(ParseError::UnknownGlyph(' ')));
+        assert_eq!(parse_lenient("ΜΒ''"), Err(ParseError::UnknownGlyph('\'')));
+        assert_eq!("Μβ'".parse::<GreekNumeral>(), Err(ParseError::MixedCase));
+    }
+
+    #[test]
+    fn test_parse_prefix() {
+        assert_eq!(parse_prefix("ΜΒ' ἔτη"), Ok((GreekNumeral(42), 5)));
+        assert_eq!(parse_prefix("μβ ἔτη"), Ok((GreekNumeral(42), 4)));
+        assert_eq!(parse_prefix("͵αϡπδʹ, ἔτος"), Ok((GreekNumeral(1984), 12)));
+        assert_eq!(parse_prefix("ΧΙϚ\u{374}"), Ok((GreekNumeral(616), 8)));
+        assert_eq!(parse_prefix("𐆊' οὐδέν"), Ok((GreekNumeral(0), 5)));
+        assert_eq!(parse_prefix("ΜΒΑ"), Ok((GreekNumeral(42), 4)));
+        assert_eq!(parse_prefix("ΜΒ''"), Ok((GreekNumeral(42), 5)));
+        assert_eq!(parse_prefix("\u{2126}Β'"), Ok((GreekNumeral(802), 6)));
+        let (numeral, len) = parse_prefix("ΡΚΓ' καί").unwrap();
+        assert_eq!((numeral.as_u32(), &"ΡΚΓ' καί"[len..]), (123, " καί"));
+        assert_eq!(parse_prefix(""), Err(ParseError::Empty));
+        assert_eq!(parse_prefix("ἔτη"), Err(ParseError::UnknownGlyph('ἔ')));
+        assert_eq!(parse_prefix("Μβ'"), Err(ParseError::MixedCase));
+    }
+
+    #[test]
+    fn test_cmp_str() {
+        assert_eq!(cmp_str("ΜΒʹ", "ΜΓʹ"), Ok(Ordering::Less));
+        assert_eq!(cmp_str("ΜΒ'", "μβʹ"), Ok(Ordering::Equal));
+        assert_eq!("Ωʹ".cmp("͵Αʹ"), Ordering::Greater);
+        assert_eq!(cmp_str("Ωʹ", "͵Αʹ"), Ok(Ordering::Less));
+        assert_eq!("ϘΑ'".cmp("Ρ'"), Ordering::Greater);
+        assert_eq!(cmp_str("ϘΑ'", "Ρ'"), Ok(Ordering::Less));
+        assert_eq!(cmp_str("ΜΒ", "ΜΓʹ"), Err(ParseError::MissingKeraia));
+        assert_eq!(cmp_str("ΜΒʹ", "ΓΜʹ"), Err(ParseError::OutOfOrder));
+        let mut refs = ["͵Αʹ", "Βʹ", "Ωʹ", "ΙΑʹ"];
+        refs.sort_by(|a, b| cmp_str(a, b).unwrap());
+        assert_eq!(refs, ["Βʹ", "ΙΑʹ", "Ωʹ", "͵Αʹ"]);
+    }
+
+    #[test]
+    fn test_from_arabic_string() {
+        let parse = GreekNumeral::from_arabic_string;
+        assert_eq!(parse("9,999"), Ok(GreekNumeral(9999)));
+        assert_eq!(parse(" 42 "), Ok(GreekNumeral(42)));
+        assert_eq!(parse("1 984"), Ok(GreekNumeral(1984)));
+        assert_eq!(parse("0"), Ok(GreekNumeral(0)));
+        assert_eq!(parse("1000000"), Err(ParseError::OutOfRange));
+        assert_eq!(parse("99999999999999"), Err(ParseError::OutOfRange));
+        assert_eq!(parse(""), Err(ParseError::Empty));
+        assert_eq!(parse(" , "), Err(ParseError::Empty));
+        assert_eq!(parse("-5"), Err(ParseError::UnknownGlyph('-')));
+        assert_eq!(parse("4.2"), Err(ParseError::UnknownGlyph('.')));
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        assert_eq!(GreekNumeral::try_from("ΜΒʹ"), Ok(GreekNumeral(42)));
+        assert_eq!(GreekNumeral::try_from("μβʹ"), Ok(GreekNumeral(42)));
+        assert_eq!(
+            GreekNumeral::try_from("XLII'"),
+            Err(ParseError::UnknownGlyph('X'))
+        );
+    }
+
+    #[test]
+    fn test_try_from_char() {
+        assert_eq!(GreekNumeral::try_from('Μ'), Ok(GreekNumeral(40)));
+        assert_eq!(GreekNumeral::try_from('μ'), Ok(GreekNumeral(40)));
+        assert_eq!(GreekNumeral::try_from('Ϡ'), Ok(GreekNumeral(900)));
+        assert_eq!(GreekNumeral::try_from('ϛ'), Ok(GreekNumeral(6)));
+        assert_eq!(GreekNumeral::try_from('ς'), Ok(GreekNumeral(200)));
+        assert_eq!(GreekNumeral::try_from('𐆊'), Ok(GreekNumeral(0)));
+        assert_eq!(
+            GreekNumeral::try_from('X'),
+            Err(ParseError::UnknownGlyph('X'))
+        );
+        assert_eq!(
+            GreekNumeral::try_from('͵'),
+            Err(ParseError::UnknownGlyph('͵'))
+        );
+        assert_eq!(
+            GreekNumeral::try_from('\''),
+            Err(ParseError::UnknownGlyph('\''))
+        );
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod std_test {
+    use super::*;
+    use crate::{Format, SixForm};
+
+    #[test]
+    fn test_parse_error_display() {
+        assert_eq!(ParseError::Empty.to_string(), "Empty Greek numeral.");
+        assert_eq!(
+            ParseError::UnknownGlyph('X').to_string(),
+            "Unknown glyph 'X' in Greek numeral."
+        );
+        let err: Box<dyn core::error::Error> = Box::new(ParseError::OutOfOrder);
+        assert_eq!(err.to_string(), "Greek numeral glyphs are out of order.");
+    }
+
+    #[test]
+    fn test_from_str_normalized() {
+        let nfd: String = "ΜΒ\u{374}".nfd().collect();
+        assert_ne!(nfd, "ΜΒ\u{374}");
+        assert_eq!(nfd.parse(), Ok(GreekNumeral(42)));
+        assert_eq!("ς'".parse(), Ok(GreekNumeral(200)));
+        assert_eq!("σ'".parse(), Ok(GreekNumeral(200)));
+        assert_eq!("͵ας'".parse(), Ok(GreekNumeral(1200)));
+        assert!(is_valid_greek_numeral("ς"));
+    }
+
+    #[test]
+    fn test_from_str_overlined() {
+        assert_eq!("Μ\u{305}Β\u{305}".parse(), Ok(GreekNumeral(42)));
+        assert_eq!("μ\u{305}β\u{305}ʹ".parse(), Ok(GreekNumeral(42)));
+        assert_eq!(
+            "͵Α\u{305}Σ\u{305}Λ\u{305}Δ\u{305}".parse(),
+            Ok(GreekNumeral(1234))
+        );
+        for i in [0, 7, 42, 616, 1984, crate::MAX] {
+            let num = GreekNumeral::new(i).unwrap();
+            assert_eq!(num.to_overlined().parse(), Ok(num));
+        }
+        assert_eq!(
+            "Β\u{305}Μ\u{305}".parse::<GreekNumeral>(),
+            Err(ParseError::OutOfOrder)
+        );
+        assert_eq!(
+            parse_prefix("Μ\u{305}Β\u{305} ἔτη"),
+            Ok((GreekNumeral(42), 8))
+        );
+    }
+
+    #[test]
+    fn test_parse_with_case() {
+        assert_eq!(parse_with_case("ΜΒ'"), Ok((GreekNumeral(42), Case::Upper)));
+        assert_eq!(parse_with_case("μβ'"), Ok((GreekNumeral(42), Case::Lower)));
+        assert_eq!(
+            parse_with_case("͵αϡπδʹ"),
+            Ok((GreekNumeral(1984), Case::Lower))
+        );
+        assert_eq!(parse_with_case("𐆊'"), Ok((GreekNumeral(0), Case::Upper)));
+        assert_eq!(parse_with_case("Μβ'"), Err(ParseError::MixedCase));
+        assert_eq!(parse_with_case("ΜΒ"), Err(ParseError::MissingKeraia));
+        for s in ["ΧΙϜ'", "χιϝ'"] {
+            let (numeral, case) = parse_with_case(s).unwrap();
+            assert_eq!(numeral.to_string_with_case(case), s);
+        }
+    }
+
+    #[test]
+    fn test_strip_keraia() {
+        assert_eq!(strip_keraia("ΜΒ\u{374}"), "ΜΒ");
+        assert_eq!(strip_keraia("ΜΒ'"), "ΜΒ");
+        assert_eq!(strip_keraia("μβʹ"), "μβ");
+        assert_eq!(strip_keraia("ΜΒ"), "ΜΒ");
+        assert_eq!(strip_keraia("ΜΒ''"), "ΜΒ'");
+        assert_eq!(strip_keraia("ΜΒ\u{34f}\u{301}"), "ΜΒ");
+        assert_eq!(strip_keraia("ΜΒ\u{301}"), "ΜΒ");
+        assert_eq!(strip_keraia("'"), "");
+        assert_eq!(strip_keraia(""), "");
+        assert_eq!(
+            strip_keraia(&GreekNumeral(42).to_uppercase()),
+            strip_keraia("ΜΒ\u{374}")
+        );
+    }
+
+    #[test]
+    fn test_canonicalize() {
+        assert_eq!(canonicalize("ΧΙϚ'"), Ok(String::from("ΧΙϜ'")));
+        assert_eq!(canonicalize("χιϛʹ"), Ok(String::from("χιϝ'")));
+        assert_eq!(canonicalize("ΧΙΣΤ'"), Ok(String::from("ΧΙϜ'")));
+        assert_eq!(canonicalize("͵σ͵τ'"), Ok(String::from("͵ϝ'")));
+        assert_eq!(canonicalize("ͲϞΘ\u{374}"), Ok(String::from("ϠϘΘ'")));
+        assert_eq!(canonicalize("͵αͳπδ'"), Ok(String::from("͵αϡπδ'")));
+        assert_eq!(canonicalize("ς'"), Ok(String::from("σ'")));
+        assert_eq!(canonicalize("𐆊'"), Ok(String::from("𐆊'")));
+        assert_eq!(canonicalize("ΜΒ'"), Ok(String::from("ΜΒ'")));
+        assert_eq!(canonicalize("ΒΜ'"), Err(ParseError::OutOfOrder));
+    }
+
+    #[test]
+    fn test_is_canonical() {
+        assert!(is_canonical("Τ'"));
+        assert!(is_canonical("υμβ'"));
+        assert!(!is_canonical("͵Ϛ'"));
+        assert!(is_canonical("͵Ϝ'"));
+        assert!(is_canonical("𐆊'"));
+        assert!(!is_canonical("ΧΙϚ'"));
+        assert!(!is_canonical("ΤΜΒʹ"));
+        assert!(!is_canonical("ΤΜΒ"));
+        assert!(!is_canonical("ΡΡ'"));
+        assert!(!is_canonical("ς'"));
+    }
+
+    #[test]
+    fn test_is_canonical_legacy() {
+        // Older versions wrote rho for 300 and 400, and ͵Ε for 6,000.
+        assert!(!is_canonical("Ρ'"));
+        assert!(!is_canonical("ρμβ'"));
+        assert!(!is_canonical("͵ΑΡ'"));
+        assert!(!is_canonical("͵Ε'"));
+        assert!(!is_canonical("͵εχ'"));
+        assert!(is_canonical("Τ'"));
+        assert!(is_canonical("Υ'"));
+        assert!(is_canonical("͵Ϝ'"));
+        assert!(is_canonical("Ε'"));
+        #[cfg(feature = "large-range")]
+        assert!(is_canonical("͵Ρ'"));
+        assert_eq!("Ρ'".parse(), Ok(GreekNumeral(100)));
+    }
+
+    proptest::proptest! {
+        #![proptest_config(proptest::test_runner::Config::with_cases(10_000))]
+
+        #[test]
+        fn test_round_trip_random_value(v in 0..=crate::MAX) {
+            let num = GreekNumeral::new(v).unwrap();
+            proptest::prop_assert_eq!(num.to_uppercase().parse(), Ok(num));
+            proptest::prop_assert_eq!(num.to_lowercase().parse(), Ok(num));
+        }
+
+        #[test]
+        fn test_round_trip_sigma_tau(v in 0..=crate::MAX) {
+            let num = GreekNumeral::new(v).unwrap();
+            for case in [Case::Upper, Case::Lower] {
+                let opts = Format { case, six: SixForm::SigmaTau, ..Format::default() };
+                proptest::prop_assert_eq!(num.format_with(opts).parse(), Ok(num));
+            }
+        }
+
+        #[test]
+        fn test_rejects_latin_strings(s in "[A-Za-z0-9 ,.]*'?") {
+            proptest::prop_assert!(s.parse::<GreekNumeral>().is_err());
+            proptest::prop_assert!(!is_valid_greek_numeral(&s));
+        }
+
+        #[test]
+        fn test_parsed_strings_render_canonically(s in "\\PC*") {
+            if let Ok(num) = s.parse::<GreekNumeral>() {
+                proptest::prop_assert_eq!(canonicalize(&s).unwrap().parse(), Ok(num));
+            }
+        }
+    }
+}
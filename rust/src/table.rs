@@ -0,0 +1,667 @@
+//! The conversion tables shared by rendering and parsing
+//!
+//! ``ARITHMOI`` is the single authoritative list of alphabetic glyphs. It must
+//! stay sorted strictly descending by value, and ``MAX`` is derived from its
+//! first entry. The myriad rows (10,000 and above) are only active with the
+//! ``large-range`` feature.
+
+use crate::Case;
+
+/// GREEK ZERO SIGN (U+1018A), the default glyph for 0 (ZERO)
+pub(crate) const ZERO: &str = "𐆊";
+
+/// ``ZERO`` followed by the keraia
+#[cfg(feature = "std")]
+pub(crate) const ZERO_KERAIA: &str = "𐆊'";
+
+/// GREEK LOWER NUMERAL SIGN (U+0375), the marker prefixed to thousands
+pub(crate) const THOUSANDS: char = '͵';
+
+/// COMBINING GRAPHEME JOINER (U+034F) and COMBINING ACUTE ACCENT (U+0301),
+/// the optional combining keraia
+///
+/// The joiner stops NFC from composing the accent with a vowel-final glyph
+/// into a tonos letter such as ``Ά``.
+pub(crate) const COMBINING_KERAIA: &str = "\u{34f}\u{301}";
+
+/// COMBINING OVERLINE (U+0305), used in place of the keraia by some editions
+pub(crate) const OVERLINE: char = '\u{305}';
+
+/// The masculine ordinal ending, as in 1ος (πρῶτος), used in place of the
+/// keraia to mark ordinals
+#[cfg(feature = "std")]
+pub(crate) const ORDINAL: &str = "ος";
+
+// based on https://en.wikipedia.org/wiki/Greek_numerals
+pub(crate) struct Arabic2GreekStruct<'a> {
+    pub(crate) arabic: u32,
+    pub(crate) u_attic: &'a str,
+    pub(crate) l_attic: &'a str,
+    /// ``u_attic`` followed by the keraia, for borrowing a complete numeral
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    pub(crate) u_keraia: &'a str,
+    /// ``l_attic`` followed by the keraia
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    pub(crate) l_keraia: &'a str,
+}
+
+impl<'a> Arabic2GreekStruct<'a> {
+    /// Returns the glyph for this entry in the given ``Case``.
+    pub(crate) const fn attic(&self, case: Case) -> &'a str {
+        match case {
+            Case::Upper => self.u_attic,
+            Case::Lower => self.l_attic,
+        }
+    }
+
+    /// Returns the glyph for this entry in the given ``Case``, followed by the
+    /// keraia.
+    #[cfg(feature = "std")]
+    pub(crate) const fn with_keraia(&self, case: Case) -> &'a str {
+        match case {
+            Case::Upper => self.u_keraia,
+            Case::Lower => self.l_keraia,
+        }
+    }
+}
+
+/// The number of decimal places covered by ``ARITHMOI``, nine entries each
+pub(crate) const PLACES: usize = if cfg!(feature = "large-range") { 6 } else { 4 };
+
+/// Returns the entry for ``digit`` (1 to 9) in decimal ``place`` (0 for the
+/// units), found by position instead of scanning the table.
+pub(crate) const fn lookup(place: usize, digit: u32) -> &'static Arabic2GreekStruct<'static> {
+    &ARITHMOI[(PLACES - 1 - place) * 9 + (9 - digit as usize)]
+}
+
+/// The glyphs for the active range: the last ``PLACES`` places of
+/// ``ALL_ARITHMOI``
+pub(crate) static ARITHMOI: &[Arabic2GreekStruct] =
+    ALL_ARITHMOI.split_at(ALL_ARITHMOI.len() - PLACES * 9).1;
+
+// ``lookup`` finds entries by position, so every entry must hold the digit
+// and place its index implies: nine per place, 9 down to 1, the largest place
+// first.
+const _: () = {
+    assert!(PLACES * 9 <= ALL_ARITHMOI.len());
+    let mut i = 0;
+    while i < ALL_ARITHMOI.len() {
+        let place = (ALL_ARITHMOI.len() - 1 - i) / 9;
+        let digit = 9 - i % 9;
+        assert!(
+            ALL_ARITHMOI[i].arabic == digit as u32 * 10_u32.pow(place as u32),
+            "ALL_ARITHMOI entry out of place"
+        );
+        i += 1;
+    }
+};
+
+static ALL_ARITHMOI: [Arabic2GreekStruct; 54] = [
+    Arabic2GreekStruct {
+        arabic: 900000,
+        u_attic: "͵Ϡ",
+        l_attic: "͵ϡ",
+        u_keraia: "͵Ϡ'",
+        l_keraia: "͵ϡ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 800000,
+        u_attic: "͵Ω",
+        l_attic: "͵ω",
+        u_keraia: "͵Ω'",
+        l_keraia: "͵ω'",
+    },
+    Arabic2GreekStruct {
+        arabic: 700000,
+        u_attic: "͵Ψ",
+        l_attic: "͵ψ",
+        u_keraia: "͵Ψ'",
+        l_keraia: "͵ψ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 600000,
+        u_attic: "͵Χ",
+        l_attic: "͵χ",
+        u_keraia: "͵Χ'",
+        l_keraia: "͵χ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 500000,
+        u_attic: "͵Φ",
+        l_attic: "͵φ",
+        u_keraia: "͵Φ'",
+        l_keraia: "͵φ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 400000,
+        u_attic: "͵Υ",
+        l_attic: "͵υ",
+        u_keraia: "͵Υ'",
+        l_keraia: "͵υ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 300000,
+        u_attic: "͵Τ",
+        l_attic: "͵τ",
+        u_keraia: "͵Τ'",
+        l_keraia: "͵τ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 200000,
+        u_attic: "͵Σ",
+        l_attic: "͵σ",
+        u_keraia: "͵Σ'",
+        l_keraia: "͵σ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 100000,
+        u_attic: "͵Ρ",
+        l_attic: "͵ρ",
+        u_keraia: "͵Ρ'",
+        l_keraia: "͵ρ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 90000,
+        u_attic: "͵Ϙ",
+        l_attic: "͵ϙ",
+        u_keraia: "͵Ϙ'",
+        l_keraia: "͵ϙ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 80000,
+        u_attic: "͵Π",
+        l_attic: "͵π",
+        u_keraia: "͵Π'",
+        l_keraia: "͵π'",
+    },
+    Arabic2GreekStruct {
+        arabic: 70000,
+        u_attic: "͵Ο",
+        l_attic: "͵ο",
+        u_keraia: "͵Ο'",
+        l_keraia: "͵ο'",
+    },
+    Arabic2GreekStruct {
+        arabic: 60000,
+        u_attic: "͵Ξ",
+        l_attic: "͵ξ",
+        u_keraia: "͵Ξ'",
+        l_keraia: "͵ξ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 50000,
+        u_attic: "͵Ν",
+        l_attic: "͵ν",
+        u_keraia: "͵Ν'",
+        l_keraia: "͵ν'",
+    },
+    Arabic2GreekStruct {
+        arabic: 40000,
+        u_attic: "͵Μ",
+        l_attic: "͵μ",
+        u_keraia: "͵Μ'",
+        l_keraia: "͵μ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 30000,
+        u_attic: "͵Λ",
+        l_attic: "͵λ",
+        u_keraia: "͵Λ'",
+        l_keraia: "͵λ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 20000,
+        u_attic: "͵Κ",
+        l_attic: "͵κ",
+        u_keraia: "͵Κ'",
+        l_keraia: "͵κ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 10000,
+        u_attic: "͵Ι",
+        l_attic: "͵ι",
+        u_keraia: "͵Ι'",
+        l_keraia: "͵ι'",
+    },
+    Arabic2GreekStruct {
+        arabic: 9000,
+        u_attic: "͵Θ",
+        l_attic: "͵θ",
+        u_keraia: "͵Θ'",
+        l_keraia: "͵θ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 8000,
+        u_attic: "͵Η",
+        l_attic: "͵η",
+        u_keraia: "͵Η'",
+        l_keraia: "͵η'",
+    },
+    Arabic2GreekStruct {
+        arabic: 7000,
+        u_attic: "͵Ζ",
+        l_attic: "͵ζ",
+        u_keraia: "͵Ζ'",
+        l_keraia: "͵ζ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 6000,
+        u_attic: "͵Ϝ",
+        l_attic: "͵ϝ",
+        u_keraia: "͵Ϝ'",
+        l_keraia: "͵ϝ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 5000,
+        u_attic: "͵Ε",
+        l_attic: "͵ε",
+        u_keraia: "͵Ε'",
+        l_keraia: "͵ε'",
+    },
+    Arabic2GreekStruct {
+        arabic: 4000,
+        u_attic: "͵Δ",
+        l_attic: "͵δ",
+        u_keraia: "͵Δ'",
+        l_keraia: "͵δ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 3000,
+        u_attic: "͵Γ",
+        l_attic: "͵γ",
+        u_keraia: "͵Γ'",
+        l_keraia: "͵γ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 2000,
+        u_attic: "͵Β",
+        l_attic: "͵β",
+        u_keraia: "͵Β'",
+        l_keraia: "͵β'",
+    },
+    Arabic2GreekStruct {
+        arabic: 1000,
+        u_attic: "͵Α",
+        l_attic: "͵α",
+        u_keraia: "͵Α'",
+        l_keraia: "͵α'",
+    },
+    Arabic2GreekStruct {
+        arabic: 900,
+        u_attic: "Ϡ",
+        l_attic: "ϡ",
+        u_keraia: "Ϡ'",
+        l_keraia: "ϡ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 800,
+        u_attic: "Ω",
+        l_attic: "ω",
+        u_keraia: "Ω'",
+        l_keraia: "ω'",
+    },
+    Arabic2GreekStruct {
+        arabic: 700,
+        u_attic: "Ψ",
+        l_attic: "ψ",
+        u_keraia: "Ψ'",
+        l_keraia: "ψ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 600,
+        u_attic: "Χ",
+        l_attic: "χ",
+        u_keraia: "Χ'",
+        l_keraia: "χ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 500,
+        u_attic: "Φ",
+        l_attic: "φ",
+        u_keraia: "Φ'",
+        l_keraia: "φ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 400,
+        u_attic: "Υ",
+        l_attic: "υ",
+        u_keraia: "Υ'",
+        l_keraia: "υ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 300,
+        u_attic: "Τ",
+        l_attic: "τ",
+        u_keraia: "Τ'",
+        l_keraia: "τ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 200,
+        u_attic: "Σ",
+        l_attic: "σ",
+        u_keraia: "Σ'",
+        l_keraia: "σ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 100,
+        u_attic: "Ρ",
+        l_attic: "ρ",
+        u_keraia: "Ρ'",
+        l_keraia: "ρ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 90,
+        u_attic: "Ϙ",
+        l_attic: "ϙ",
+        u_keraia: "Ϙ'",
+        l_keraia: "ϙ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 80,
+        u_attic: "Π",
+        l_attic: "π",
+        u_keraia: "Π'",
+        l_keraia: "π'",
+    },
+    Arabic2GreekStruct {
+        arabic: 70,
+        u_attic: "Ο",
+        l_attic: "ο",
+        u_keraia: "Ο'",
+        l_keraia: "ο'",
+    },
+    Arabic2GreekStruct {
+        arabic: 60,
+        u_attic: "Ξ",
+        l_attic: "ξ",
+        u_keraia: "Ξ'",
+        l_keraia: "ξ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 50,
+        u_attic: "Ν",
+        l_attic: "ν",
+        u_keraia: "Ν'",
+        l_keraia: "ν'",
+    },
+    Arabic2GreekStruct {
+        arabic: 40,
+        u_attic: "Μ",
+        l_attic: "μ",
+        u_keraia: "Μ'",
+        l_keraia: "μ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 30,
+        u_attic: "Λ",
+        l_attic: "λ",
+        u_keraia: "Λ'",
+        l_keraia: "λ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 20,
+        u_attic: "Κ",
+        l_attic: "κ",
+        u_keraia: "Κ'",
+        l_keraia: "κ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 10,
+        u_attic: "Ι",
+        l_attic: "ι",
+        u_keraia: "Ι'",
+        l_keraia: "ι'",
+    },
+    Arabic2GreekStruct {
+        arabic: 9,
+        u_attic: "Θ",
+        l_attic: "θ",
+        u_keraia: "Θ'",
+        l_keraia: "θ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 8,
+        u_attic: "Η",
+        l_attic: "η",
+        u_keraia: "Η'",
+        l_keraia: "η'",
+    },
+    Arabic2GreekStruct {
+        arabic: 7,
+        u_attic: "Ζ",
+        l_attic: "ζ",
+        u_keraia: "Ζ'",
+        l_keraia: "ζ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 6,
+        u_attic: "Ϝ",
+        l_attic: "ϝ",
+        u_keraia: "Ϝ'",
+        l_keraia: "ϝ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 5,
+        u_attic: "Ε",
+        l_attic: "ε",
+        u_keraia: "Ε'",
+        l_keraia: "ε'",
+    },
+    Arabic2GreekStruct {
+        arabic: 4,
+        u_attic: "Δ",
+        l_attic: "δ",
+        u_keraia: "Δ'",
+        l_keraia: "δ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 3,
+        u_attic: "Γ",
+        l_attic: "γ",
+        u_keraia: "Γ'",
+        l_keraia: "γ'",
+    },
+    Arabic2GreekStruct {
+        arabic: 2,
+        u_attic: "Β",
+        l_attic: "β",
+        u_keraia: "Β'",
+        l_keraia: "β'",
+    },
+    Arabic2GreekStruct {
+        arabic: 1,
+        u_attic: "Α",
+        l_attic: "α",
+        u_keraia: "Α'",
+        l_keraia: "α'",
+    },
+];
+
+// based on https://en.wikipedia.org/wiki/Attic_numerals
+#[cfg(feature = "std")]
+pub(crate) struct Arabic2AcrophonicStruct<'a> {
+    pub(crate) arabic: u32,
+    pub(crate) attic: &'a str,
+}
+
+#[cfg(feature = "std")]
+pub(crate) static ACROPHONIC: [Arabic2AcrophonicStruct; 10] = [
+    Arabic2AcrophonicStruct {
+        arabic: 50000,
+        attic: "𐅇",
+    },
+    Arabic2AcrophonicStruct {
+        arabic: 10000,
+        attic: "Μ",
+    },
+    Arabic2AcrophonicStruct {
+        arabic: 5000,
+        attic: "𐅆",
+    },
+    Arabic2AcrophonicStruct {
+        arabic: 1000,
+        attic: "Χ",
+    },
+    Arabic2AcrophonicStruct {
+        arabic: 500,
+        attic: "𐅅",
+    },
+    Arabic2AcrophonicStruct {
+        arabic: 100,
+        attic: "Η",
+    },
+    Arabic2AcrophonicStruct {
+        arabic: 50,
+        attic: "𐅄",
+    },
+    Arabic2AcrophonicStruct {
+        arabic: 10,
+        attic: "Δ",
+    },
+    Arabic2AcrophonicStruct {
+        arabic: 5,
+        attic: "𐅃",
+    },
+    Arabic2AcrophonicStruct {
+        arabic: 1,
+        attic: "Ι",
+    },
+];
+
+/// Beta Code for each lowercase glyph in ``ARITHMOI``; capitals are the same
+/// code prefixed with ``*``
+///
+/// Based on the TLG Beta Code manual, where ``#3`` is koppa and ``#5`` sampi.
+#[cfg(feature = "std")]
+pub(crate) static BETA_CODE: [(char, &str); 27] = [
+    ('α', "A"),
+    ('β', "B"),
+    ('γ', "G"),
+    ('δ', "D"),
+    ('ε', "E"),
+    ('ϝ', "V"),
+    ('ζ', "Z"),
+    ('η', "H"),
+    ('θ', "Q"),
+    ('ι', "I"),
+    ('κ', "K"),
+    ('λ', "L"),
+    ('μ', "M"),
+    ('ν', "N"),
+    ('ξ', "C"),
+    ('ο', "O"),
+    ('π', "P"),
+    ('ϙ', "#3"),
+    ('ρ', "R"),
+    ('σ', "S"),
+    ('τ', "T"),
+    ('υ', "U"),
+    ('φ', "F"),
+    ('χ', "X"),
+    ('ψ', "Y"),
+    ('ω', "W"),
+    ('ϡ', "#5"),
+];
+
+/// Beta Code for GREEK LOWER NUMERAL SIGN (U+0375), the thousands marker
+#[cfg(feature = "std")]
+pub(crate) const BETA_CODE_THOUSANDS: &str = "#22";
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::MAX;
+
+    #[test]
+    fn test_arithmoi_sorted_descending() {
+        for pair in ARITHMOI.windows(2) {
+            assert!(
+                pair[0].arabic > pair[1].arabic,
+                "{} must come before {}",
+                pair[0].arabic,
+                pair[1].arabic,
+            );
+        }
+        assert_eq!(ARITHMOI[ARITHMOI.len() - 1].arabic, 1);
+    }
+
+    #[test]
+    fn test_lookup_by_place() {
+        assert_eq!(ARITHMOI.len() % 9, 0);
+        for place in 0..PLACES {
+            for digit in 1..=9 {
+                let expected = digit * 10_u32.pow(place as u32);
+                assert_eq!(lookup(place, digit).arabic, expected);
+                let scanned = ARITHMOI.iter().find(|arithmos| arithmos.arabic == expected);
+                assert!(scanned.is_some_and(|entry| core::ptr::eq(entry, lookup(place, digit))));
+            }
+        }
+    }
+
+    #[test]
+    fn test_max_matches_largest_place() {
+        let largest = ARITHMOI[0].arabic;
+        assert_eq!(largest % 9, 0);
+        assert_eq!(MAX, largest / 9 * 10 - 1);
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod std_test {
+    use super::*;
+
+    /// Pairs of values allowed to share a glyph. None should, now that the
+    /// 300/400 and 6000 entries are fixed.
+    const ALLOWED_COLLISIONS: [(u32, u32); 0] = [];
+
+    #[test]
+    fn test_arithmoi_glyphs_distinct() {
+        let mut collisions = Vec::new();
+        for (i, a) in ALL_ARITHMOI.iter().enumerate() {
+            for b in &ALL_ARITHMOI[i + 1..] {
+                for case in [Case::Upper, Case::Lower] {
+                    let allowed = ALLOWED_COLLISIONS.contains(&(a.arabic, b.arabic));
+                    if a.attic(case) == b.attic(case) && !allowed {
+                        collisions.push(format!(
+                            "{} and {} are both {:?} ({case:?})",
+                            a.arabic,
+                            b.arabic,
+                            a.attic(case),
+                        ));
+                    }
+                }
+            }
+        }
+        assert!(
+            collisions.is_empty(),
+            "glyph collisions:\n{}",
+            collisions.join("\n")
+        );
+    }
+
+    #[test]
+    fn test_keraia_glyphs_match_attic() {
+        assert_eq!(ZERO_KERAIA, format!("{ZERO}'"));
+        for arithmos in ALL_ARITHMOI.iter() {
+            for case in [Case::Upper, Case::Lower] {
+                assert_eq!(
+                    arithmos.with_keraia(case),
+                    format!("{}'", arithmos.attic(case))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_beta_code_covers_every_glyph() {
+        for arithmos in ALL_ARITHMOI.iter() {
+            for c in arithmos.l_attic.chars().filter(|&c| c != THOUSANDS) {
+                assert!(
+                    BETA_CODE.iter().any(|&(glyph, _)| glyph == c),
+                    "no Beta Code for {c:?} ({})",
+                    arithmos.arabic
+                );
+            }
+        }
+    }
+}
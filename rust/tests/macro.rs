@@ -0,0 +1,7 @@
+#[test]
+fn test_greek_macro() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/greek_in_range.rs");
+    t.pass("tests/ui/greek_shadowed_prelude.rs");
+    t.compile_fail("tests/ui/greek_out_of_range.rs");
+}
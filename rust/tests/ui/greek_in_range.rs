@@ -0,0 +1,8 @@
+use arithmos::{GreekNumeral, greek};
+
+const ANSWER: GreekNumeral = greek!(42);
+
+fn main() {
+    assert_eq!(ANSWER.as_u32(), 42);
+    assert_eq!(greek!(9999).as_u32(), 9999);
+}
@@ -0,0 +1,7 @@
+use arithmos::{GreekNumeral, greek};
+
+const TOO_BIG: GreekNumeral = greek!(1_000_000);
+
+fn main() {
+    let _ = TOO_BIG;
+}
@@ -0,0 +1,23 @@
+#![allow(dead_code)]
+
+use arithmos::{GreekNumeral, greek};
+
+// Shadow the prelude names the macro could otherwise pick up.
+enum Shadow {
+    Ok(u32),
+    Err(u32),
+}
+use Shadow::{Err, Ok};
+
+macro_rules! panic {
+    ($($arg:tt)*) => {
+        compile_error!("greek! must not use a local panic!")
+    };
+}
+
+const ANSWER: GreekNumeral = greek!(42);
+
+fn main() {
+    assert_eq!(ANSWER.as_u32(), 42);
+    assert_eq!(greek!(616).as_u32(), 616);
+}